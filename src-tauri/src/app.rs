@@ -1,16 +1,27 @@
 // src/app.rs
+use crate::config_manager::get_config_backups;
+use crate::python_env::{
+    is_python_version_range, is_supported_python_version, select_python_series_in_range,
+};
 use crate::utils::defender::is_defender_excluded;
+use crate::utils::file::backup_file;
 use crate::utils::path;
 use crate::utils::path::{get_app_base_path, get_app_working_dir_path};
 use anyhow::{anyhow, Context};
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeSet;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::vec::Vec;
 use tracing::{debug, error, info, warn};
 
 pub const YML_FILE_NAME: &str = "pyappify.yml";
+/// Env var naming an app definition that replaces the launcher's `pyappify.yml`, same as `--yml`.
+pub const YML_OVERRIDE_ENV_VAR: &str = "PYAPPIFY_YML";
+/// Layout version of app.json. Bump it together with a step in `migrate_app_json` whenever a
+/// field is renamed or restructured.
+pub const APP_SCHEMA_VERSION: u32 = 1;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct App {
@@ -19,24 +30,170 @@ pub struct App {
     pub current_version: Option<String>,
     #[serde(default, skip_serializing)]
     pub current_version_missing: bool,
+    /// The installed version's tag was deleted or renamed upstream.
+    #[serde(default, skip_deserializing)]
+    pub current_version_removed: bool,
+    /// Closest release still published upstream, offered when `current_version_removed`.
+    #[serde(default, skip_deserializing)]
+    pub nearest_surviving_version: Option<String>,
+    /// Set when HEAD is on no tag, e.g. "v1.2.3+4" or "untagged (abc1234)".
+    #[serde(default)]
+    pub head_description: Option<String>,
+    /// Full hash of the commit the working dir was built from, even when `current_version` is a tag.
+    #[serde(default)]
+    pub current_commit: Option<String>,
     #[serde(default)]
     pub app_starting_version: Option<String>,
+    /// Version the last update moved away from, exported as PYAPPIFY_PREVIOUS_VERSION.
+    #[serde(default)]
+    pub previous_version: Option<String>,
+    /// Set by an update and cleared by the next start, which exports PYAPPIFY_JUST_UPDATED=1.
+    #[serde(default)]
+    pub just_updated: bool,
     #[serde(default)]
     pub update_note: Vec<String>,
     #[serde(default)]
     pub available_versions: Vec<String>,
+    /// The repository has no tags, so `available_versions` only holds its default branch, which
+    /// is updated to the branch's newest commit and always counts as the latest version.
+    #[serde(default)]
+    pub tracks_branch: bool,
     #[serde(default)]
     pub running: bool,
     #[serde(default = "default_last_start_fn")]
     pub last_start: DateTime<Utc>,
+    /// When the app's tags were last fetched, used by the 'Fetch On Startup' interval.
+    #[serde(default)]
+    pub last_fetched: Option<DateTime<Utc>>,
     #[serde(default)]
     pub current_profile: String,
     #[serde(default)]
     pub installed: bool,
+    /// When setup first completed, for the 'App Sort Order' install date order.
+    #[serde(default)]
+    pub installed_at: Option<DateTime<Utc>>,
+    /// Which parts of the install are on disk, recomputed whenever apps are emitted.
+    #[serde(default, skip_deserializing)]
+    pub install_state: InstallState,
     #[serde(default)]
     pub profiles: Vec<Profile>,
     #[serde(default)]
     pub show_add_defender: bool,
+    #[serde(default, skip_deserializing)]
+    pub operation_state: OperationState,
+    /// What the running app last wrote to PYAPPIFY_STATUS_FILE.
+    #[serde(default, skip_deserializing)]
+    pub reported_status: Option<ReportedStatus>,
+    #[serde(default)]
+    pub last_run: Option<LastRun>,
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    #[serde(default)]
+    pub start_order: i32,
+    #[serde(default)]
+    pub homepage: Option<String>,
+    /// Set when a profile's `requires_python` is not a supported version; setup then falls back
+    /// to the 'Default Python Version' setting.
+    #[serde(default, skip_deserializing)]
+    pub python_version_warning: Option<String>,
+    /// app.json files written before schema_version existed read as 0.
+    #[serde(default)]
+    pub schema_version: u32,
+    /// What setup last installed into the app's Python, so setting up another profile that
+    /// resolves to the same requirements skips pip.
+    #[serde(default)]
+    pub installed_requirements: Option<InstalledRequirements>,
+    /// Recorded before an update checks out the new version and cleared once it completes or
+    /// rolls back, so an update that was interrupted or could not roll itself back can be rolled
+    /// back later.
+    #[serde(default)]
+    pub pending_update: Option<PendingUpdate>,
+}
+
+/// The profile whose requirements are installed and a hash of everything that decided them.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InstalledRequirements {
+    pub profile: String,
+    pub signature: String,
+}
+
+/// Where an unfinished update started from and where it was going.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PendingUpdate {
+    pub from_version: Option<String>,
+    pub from_revision: Option<String>,
+    pub to_version: String,
+}
+
+/// Status a running app reports about itself by writing `{"version", "ready", "message"}` JSON to
+/// the file named by PYAPPIFY_STATUS_FILE. Every field is optional.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ReportedStatus {
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub ready: Option<bool>,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// How the most recent launch of the app ended.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LastRun {
+    pub exit_code: Option<i32>,
+    /// Only set for an exit the user did not ask for; a run ended by `stop_app` never crashed.
+    pub crashed: bool,
+    #[serde(default)]
+    pub stopped_by_user: bool,
+    pub finished_at: DateTime<Utc>,
+}
+
+/// Phase of the long-running operation an app is in, shown by the UI as a per-app spinner label.
+/// Never restored from app.json, so a crash mid-setup does not leave the app looking busy.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationState {
+    #[default]
+    Idle,
+    Cloning,
+    DownloadingPython,
+    Installing,
+    Updating,
+    Starting,
+    Stopping,
+}
+
+/// How much of an app is on disk, so the UI can offer a full install or a repair of the Python
+/// environment instead of a generic setup.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallState {
+    #[default]
+    NotInstalled,
+    /// The repository was cloned but no working dir was checked out from it.
+    RepoOnly,
+    /// The working dir exists but its Python is gone or setup never finished installing it.
+    EnvMissing,
+    Installed,
+}
+
+impl InstallState {
+    pub fn derive(
+        installed: bool,
+        repo_exists: bool,
+        working_dir_exists: bool,
+        runtime_exists: bool,
+    ) -> Self {
+        if !repo_exists {
+            InstallState::NotInstalled
+        } else if !working_dir_exists {
+            InstallState::RepoOnly
+        } else if !runtime_exists || !installed {
+            InstallState::EnvMissing
+        } else {
+            InstallState::Installed
+        }
+    }
 }
 
 fn default_last_start_fn() -> DateTime<Utc> {
@@ -72,21 +229,141 @@ pub struct Profile {
     #[serde(default)]
     pub main_script: String,
     #[serde(default)]
+    pub module: String,
+    #[serde(default)]
+    pub cwd: String,
+    #[serde(default)]
     pub admin: Option<bool>,
+    /// Launches elevated through runas until one elevated run exits successfully, then
+    /// unelevated, for apps that only need admin to install a service or driver.
+    #[serde(default)]
+    pub admin_first_run: Option<bool>,
     #[serde(default)]
     pub use_pythonw: Option<bool>,
     #[serde(default)]
+    pub show_console: Option<bool>,
+    #[serde(default)]
     pub show_add_defender: Option<bool>,
+    /// Requirement files and specs installed together, e.g. `requirements/base.txt` and
+    /// `requirements/gui.txt`. A single string is read as a one-entry list.
+    #[serde(default, deserialize_with = "deserialize_requirements")]
+    pub requirements: Vec<String>,
+    /// pyproject.toml optional-dependencies installed with a project spec, e.g. `gui` turns `.`
+    /// into `.[gui]`.
     #[serde(default)]
-    pub requirements: String,
+    pub extras: Vec<String>,
     #[serde(default, rename = "PYTHONPATH")]
     pub python_path: String,
     #[serde(default)]
     pub git_url: String,
+    /// Only tags matching this glob are fetched and offered as versions, e.g. `v2.*`. All tags
+    /// when omitted.
+    #[serde(default)]
+    pub tag_glob: Option<String>,
     #[serde(default)]
     pub requires_python: String,
     #[serde(default)]
     pub pip_args: String,
+    #[serde(default)]
+    pub clear_env: Vec<String>,
+    #[serde(default)]
+    pub keep_env: Vec<String>,
+    /// Command run after an update has synced the working dir and dependencies, e.g.
+    /// "python manage.py migrate". A non-zero exit fails the update.
+    #[serde(default)]
+    pub migrate: String,
+    /// Working dir paths holding the app's own data, e.g. `saves` or `config/user.json`. Syncing
+    /// from the repo, reinstalling and `uninstall_app` with `keep_data` leave them in place,
+    /// though files the repo itself has are still updated from it.
+    #[serde(default)]
+    pub preserve_paths: Vec<String>,
+    /// PYTHONIOENCODING of the app; utf-8 when omitted, an empty string leaves it unset so the
+    /// app uses the system code page.
+    #[serde(default)]
+    pub io_encoding: Option<String>,
+    /// Sets PYTHONUNBUFFERED so output streams to the launcher line by line; defaults to true.
+    /// False leaves the app's output buffered, which some apps need for throughput.
+    #[serde(default)]
+    pub python_unbuffered: Option<bool>,
+    /// Launches a prebuilt executable from the repo: setup installs neither Python nor
+    /// requirements, and entries must name an executable rather than a script or module.
+    #[serde(default)]
+    pub no_python: Option<bool>,
+    #[serde(default)]
+    pub entries: Vec<Entry>,
+    #[serde(default)]
+    pub health_check: Option<HealthCheck>,
+}
+
+fn deserialize_requirements<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+    let specs = match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(spec) => vec![spec],
+        OneOrMany::Many(specs) => specs,
+    };
+    Ok(specs
+        .into_iter()
+        .map(|spec| spec.trim().to_string())
+        .filter(|spec| !spec.is_empty())
+        .collect())
+}
+
+/// Readiness probe of a running app. The first non-empty field is used: `command` must exit
+/// with 0, `url` must answer a GET with a 2xx status, `file` must exist. Relative paths are
+/// resolved against the profile's cwd.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct HealthCheck {
+    #[serde(default)]
+    pub command: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub file: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Healthy,
+    Unhealthy,
+    Unknown,
+}
+
+/// A named launch target of a profile; apps bundling several tools list one per tool.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct Entry {
+    pub name: String,
+    #[serde(default)]
+    pub main_script: String,
+    #[serde(default)]
+    pub module: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+impl Entry {
+    pub fn validate(&self) -> anyhow::Result<()> {
+        match (self.main_script.is_empty(), self.module.is_empty()) {
+            (true, true) => Err(anyhow!(
+                "Neither main_script nor module is set for entry '{}'.",
+                self.name
+            )),
+            (false, false) => Err(anyhow!(
+                "main_script '{}' and module '{}' are mutually exclusive in entry '{}'.",
+                self.main_script,
+                self.module,
+                self.name
+            )),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl Profile {
@@ -94,6 +371,10 @@ impl Profile {
         self.admin.unwrap_or(false)
     }
 
+    pub fn admin_first_run(&self) -> bool {
+        self.admin_first_run.unwrap_or(false)
+    }
+
     pub fn use_pythonw(&self) -> bool {
         self.use_pythonw.unwrap_or(false)
     }
@@ -101,17 +382,114 @@ impl Profile {
     pub fn show_add_defender(&self) -> bool {
         self.show_add_defender.unwrap_or(false)
     }
+
+    pub fn show_console(&self) -> bool {
+        self.show_console.unwrap_or(false)
+    }
+
+    pub fn no_python(&self) -> bool {
+        self.no_python.unwrap_or(false)
+    }
+
+    /// `preserve_paths` relative to the working dir. Entries that are absolute or climb out of
+    /// it with `..` are skipped.
+    pub fn preserved_paths(&self) -> Vec<PathBuf> {
+        self.preserve_paths
+            .iter()
+            .map(|entry| PathBuf::from(entry.trim()))
+            .filter(|path| {
+                let valid = !path.as_os_str().is_empty()
+                    && path
+                        .components()
+                        .all(|component| matches!(component, Component::Normal(_)));
+                if !valid {
+                    warn!("Ignoring preserve_paths entry '{}'", path.display());
+                }
+                valid
+            })
+            .collect()
+    }
+
+    pub fn io_encoding(&self) -> Option<&str> {
+        match self.io_encoding.as_deref().map(str::trim) {
+            None => Some("utf-8"),
+            Some("") => None,
+            Some(encoding) => Some(encoding),
+        }
+    }
+
+    pub fn python_unbuffered(&self) -> bool {
+        self.python_unbuffered.unwrap_or(true)
+    }
+
+    /// `cwd` inside the working dir. Like `preserve_paths`, a `cwd` that is absolute or climbs out
+    /// of it with `..` is ignored and the working dir is used instead.
+    pub fn resolve_cwd(&self, working_dir: &Path) -> PathBuf {
+        let cwd = Path::new(self.cwd.trim());
+        if cwd.as_os_str().is_empty() {
+            return working_dir.to_path_buf();
+        }
+        let valid = cwd
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+        if !valid {
+            warn!("Ignoring cwd '{}' outside the working dir", cwd.display());
+            return working_dir.to_path_buf();
+        }
+        working_dir.join(cwd)
+    }
+
+    /// The profile's entries, or a single entry built from `main_script`/`module` when none
+    /// are declared.
+    pub fn launch_entries(&self) -> Vec<Entry> {
+        if self.entries.is_empty() {
+            vec![Entry {
+                name: self.name.clone(),
+                main_script: self.main_script.clone(),
+                module: self.module.clone(),
+                args: Vec::new(),
+            }]
+        } else {
+            self.entries.clone()
+        }
+    }
+
+    pub fn get_entry(&self, entry_name: Option<&str>) -> anyhow::Result<Entry> {
+        let entries = self.launch_entries();
+        let entry = match entry_name {
+            Some(name) if !name.is_empty() => entries.into_iter().find(|e| e.name == name),
+            _ => entries.into_iter().next(),
+        };
+        entry.ok_or_else(|| {
+            anyhow!(
+                "Entry '{}' not found in profile '{}'.",
+                entry_name.unwrap_or_default(),
+                self.name
+            )
+        })
+    }
 }
 
 fn apply_profile_inheritance(config: &mut App) {
     if let Some(first_profile) = config.profiles.first().cloned() {
         for profile in config.profiles.iter_mut().skip(1) {
-            if profile.main_script.is_empty() {
+            if profile.main_script.is_empty()
+                && profile.module.is_empty()
+                && profile.entries.is_empty()
+            {
                 profile.main_script = first_profile.main_script.clone();
+                profile.module = first_profile.module.clone();
+                profile.entries = first_profile.entries.clone();
+            }
+            if profile.cwd.is_empty() {
+                profile.cwd = first_profile.cwd.clone();
             }
             if profile.requirements.is_empty() {
                 profile.requirements = first_profile.requirements.clone();
             }
+            if profile.extras.is_empty() {
+                profile.extras = first_profile.extras.clone();
+            }
             if profile.python_path.is_empty() {
                 profile.python_path = first_profile.python_path.clone();
             }
@@ -121,45 +499,179 @@ fn apply_profile_inheritance(config: &mut App) {
             if profile.requires_python.is_empty() {
                 profile.requires_python = first_profile.requires_python.clone();
             }
+            if profile.tag_glob.is_none() {
+                profile.tag_glob = first_profile.tag_glob.clone();
+            }
             if profile.admin.is_none() {
                 profile.admin = first_profile.admin;
             }
+            if profile.admin_first_run.is_none() {
+                profile.admin_first_run = first_profile.admin_first_run;
+            }
             if profile.use_pythonw.is_none() {
                 profile.use_pythonw = first_profile.use_pythonw;
             }
+            if profile.show_console.is_none() {
+                profile.show_console = first_profile.show_console;
+            }
             if profile.show_add_defender.is_none() {
                 profile.show_add_defender = first_profile.show_add_defender;
             }
             if profile.pip_args.is_empty() {
                 profile.pip_args = first_profile.pip_args.clone();
             }
+            if profile.clear_env.is_empty() {
+                profile.clear_env = first_profile.clear_env.clone();
+            }
+            if profile.keep_env.is_empty() {
+                profile.keep_env = first_profile.keep_env.clone();
+            }
+            if profile.health_check.is_none() {
+                profile.health_check = first_profile.health_check.clone();
+            }
+            if profile.migrate.is_empty() {
+                profile.migrate = first_profile.migrate.clone();
+            }
+            if profile.preserve_paths.is_empty() {
+                profile.preserve_paths = first_profile.preserve_paths.clone();
+            }
+            if profile.io_encoding.is_none() {
+                profile.io_encoding = first_profile.io_encoding.clone();
+            }
+            if profile.python_unbuffered.is_none() {
+                profile.python_unbuffered = first_profile.python_unbuffered;
+            }
+            if profile.no_python.is_none() {
+                profile.no_python = first_profile.no_python;
+            }
         }
     }
+    config.python_version_warning = unsupported_python_version_warning(&config.profiles);
 }
 
-pub fn read_embedded_app() -> App {
-    let yml_content = fs::read_to_string("pyappify.yml")
-        .unwrap_or_else(|_| include_str!("../assets/pyappify.yml").to_string());
-    let mut app: App = serde_yaml::from_str(&yml_content).expect("Failed to parse pyappify.yml");
-    let working_pyappify = get_app_working_dir_path(app.name.as_str());
-    let working_pyappify_contents = fs::read_to_string(working_pyappify);
-    if let Ok(contents) = working_pyappify_contents {
-        if let Ok(new_app) = serde_yaml::from_str(&contents) {
-            app = new_app;
-        } else {
-            error!("error!: Failed to parse working dir pyappify.yml");
+/// Warns about profiles whose `requires_python` setup cannot honour: a single version that is not
+/// supported falls back to the default Python, a range no supported version satisfies fails.
+fn unsupported_python_version_warning(profiles: &[Profile]) -> Option<String> {
+    let mut unsupported = Vec::new();
+    let mut unsatisfiable = Vec::new();
+    for profile in profiles {
+        let requires_python = profile.requires_python.trim();
+        let described = format!("{} ({})", profile.name, requires_python);
+        if requires_python.is_empty() {
+            continue;
+        } else if is_python_version_range(requires_python) {
+            if select_python_series_in_range(requires_python, "").is_err() {
+                unsatisfiable.push(described);
+            }
+        } else if !is_supported_python_version(requires_python) {
+            unsupported.push(described);
         }
     }
+    let mut warnings = Vec::new();
+    if !unsupported.is_empty() {
+        warnings.push(format!(
+            "Unsupported requires_python in profile(s) {}, the default Python version will be used.",
+            unsupported.join(", ")
+        ));
+    }
+    if !unsatisfiable.is_empty() {
+        warnings.push(format!(
+            "No supported Python version satisfies requires_python in profile(s) {}, setup will fail.",
+            unsatisfiable.join(", ")
+        ));
+    }
+    if warnings.is_empty() {
+        return None;
+    }
+    let warning = warnings.join(" ");
+    warn!("{}", warning);
+    Some(warning)
+}
+
+/// The app definition passed with `--yml <path>` or `PYAPPIFY_YML`; the flag wins over the env var.
+fn yml_override_path() -> Option<PathBuf> {
+    let args: Vec<String> = std::env::args().collect();
+    args.iter()
+        .position(|arg| arg == "--yml")
+        .and_then(|i| args.get(i + 1).cloned())
+        .or_else(|| std::env::var(YML_OVERRIDE_ENV_VAR).ok())
+        .filter(|path| !path.trim().is_empty())
+        .map(PathBuf::from)
+}
+
+/// Reads the launcher's own `pyappify.yml`, preferring the copy in the app's working dir when it
+/// parses. Fails only when the launcher's yml itself is malformed. A `--yml`/`PYAPPIFY_YML`
+/// override replaces both and must exist and parse.
+pub fn read_embedded_app() -> anyhow::Result<App> {
+    let mut app: App = if let Some(override_path) = yml_override_path() {
+        let yml_content = fs::read_to_string(&override_path)
+            .with_context(|| format!("Failed to read {}", override_path.display()))?;
+        serde_yaml::from_str(&yml_content)
+            .with_context(|| format!("Failed to parse {}", override_path.display()))?
+    } else {
+        let mut app = parse_local_or_bundled_yml(fs::read_to_string("pyappify.yml").ok())?;
+        let working_pyappify = get_app_working_dir_path(app.name.as_str());
+        let working_pyappify_contents = fs::read_to_string(working_pyappify);
+        if let Ok(contents) = working_pyappify_contents {
+            if let Ok(new_app) = serde_yaml::from_str(&contents) {
+                app = new_app;
+            } else {
+                error!("error!: Failed to parse working dir pyappify.yml");
+            }
+        }
+        app
+    };
     apply_profile_inheritance(&mut app);
     if app.current_profile.is_empty() {
-        app.current_profile = app.profiles.first().unwrap().name.clone();
+        app.current_profile = app
+            .profiles
+            .first()
+            .map(|profile| profile.name.clone())
+            .ok_or_else(|| anyhow!("pyappify.yml defines no profiles"))?;
         info!(
             "app current_profile is empty, set to first profile: {}",
             &app.current_profile
         );
     }
-    app
+    Ok(app)
+}
+
+const BUNDLED_YML: &str = include_str!("../assets/pyappify.yml");
+
+/// The app from a `./pyappify.yml` next to the launcher, or from the bundled one when there is
+/// none or it does not parse.
+fn parse_local_or_bundled_yml(local_yml: Option<String>) -> anyhow::Result<App> {
+    if let Some(yml_content) = local_yml {
+        match serde_yaml::from_str(&yml_content) {
+            Ok(app) => return Ok(app),
+            Err(e) => warn!(
+                "Failed to parse ./pyappify.yml, using the bundled one instead: {}",
+                e
+            ),
+        }
+    }
+    serde_yaml::from_str(BUNDLED_YML).context("Failed to parse the bundled pyappify.yml")
+}
+
+/// Reads a standalone `pyappify.yml`, e.g. one handed to `add_app`, with profile inheritance
+/// applied and the first profile selected.
+pub fn read_app_from_yml(file_path: &Path) -> anyhow::Result<App> {
+    let yml_content = fs::read_to_string(file_path)
+        .with_context(|| format!("Failed to read {}", file_path.display()))?;
+    let mut app: App = serde_yaml::from_str(&yml_content)
+        .with_context(|| format!("Failed to parse {}", file_path.display()))?;
+    if app.name.is_empty() {
+        return Err(anyhow!("{} has no app name", file_path.display()));
+    }
+    apply_profile_inheritance(&mut app);
+    app.current_profile = app
+        .profiles
+        .first()
+        .map(|profile| profile.name.clone())
+        .ok_or_else(|| anyhow!("{} defines no profiles", file_path.display()))?;
+    Ok(app)
 }
+
 pub fn update_app_from_yml(app: &mut App, file_path_str: &str) {
     let file_path = Path::new(file_path_str);
 
@@ -198,6 +710,10 @@ pub fn update_app_from_yml(app: &mut App, file_path_str: &str) {
     apply_profile_inheritance(&mut parsed_app);
 
     app.profiles = parsed_app.profiles;
+    app.depends_on = parsed_app.depends_on;
+    app.start_order = parsed_app.start_order;
+    app.homepage = parsed_app.homepage;
+    app.python_version_warning = parsed_app.python_version_warning;
 
     if app.get_profile(&app.current_profile).is_none() {
         if let Some(first_profile) = app.profiles.first() {
@@ -210,9 +726,36 @@ fn get_app_config_json_path(app_name: &str) -> PathBuf {
     get_app_base_path(app_name).join("app.json")
 }
 
+/// Fields the UI receives but app.json must not keep: they are recomputed on every load, or
+/// describe a run or operation of this pyappify process. Their `skip_deserializing` keeps stale
+/// values from older app.json files out.
+const RUNTIME_ONLY_FIELDS: &[&str] = &[
+    "current_version_removed",
+    "nearest_surviving_version",
+    "install_state",
+    "operation_state",
+    "reported_status",
+    "python_version_warning",
+];
+
+/// The app.json form of `app`: the emitted form without `RUNTIME_ONLY_FIELDS`, stamped with
+/// the current schema version.
+fn to_persisted_json(app: &App) -> serde_json::Result<serde_json::Value> {
+    let mut json_value = serde_json::to_value(app)?;
+    if let Some(fields) = json_value.as_object_mut() {
+        for field in RUNTIME_ONLY_FIELDS {
+            fields.remove(*field);
+        }
+    }
+    json_value["schema_version"] = APP_SCHEMA_VERSION.into();
+    Ok(json_value)
+}
+
 pub(crate) async fn save_app_config_to_json(app: &App) -> anyhow::Result<()> {
     let config_path = get_app_config_json_path(&app.name);
-    let json_data = serde_json::to_string_pretty(app)
+    let json_value = to_persisted_json(app)
+        .with_context(|| format!("Failed to serialize app config for {}", app.name))?;
+    let json_data = serde_json::to_string_pretty(&json_value)
         .with_context(|| format!("Failed to serialize app config for {}", app.name))?;
     if let Some(parent) = config_path.parent() {
         tokio::fs::create_dir_all(parent).await.with_context(|| {
@@ -233,6 +776,122 @@ pub(crate) async fn save_app_config_to_json(app: &App) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Keeps a timestamped copy of the app's app.json before it is rewritten by an update or a
+/// migration, rotating out all but the 'Config Backups' newest copies.
+pub(crate) fn backup_app_config(app_name: &str) {
+    let config_path = get_app_config_json_path(app_name);
+    match backup_file(&config_path, get_config_backups()) {
+        Ok(Some(backup_path)) => debug!(
+            "Backed up app.json for {} to {}",
+            app_name,
+            backup_path.display()
+        ),
+        Ok(None) => {}
+        Err(e) => warn!("Failed to back up app.json for {}: {:#}", app_name, e),
+    }
+}
+
+/// Moves an unreadable app.json aside to `app.json.corrupt` so it can be recreated without losing
+/// the original for inspection.
+pub(crate) async fn backup_corrupt_app_config(app_name: &str) -> anyhow::Result<PathBuf> {
+    let config_path = get_app_config_json_path(app_name);
+    let backup_path = config_path.with_extension("json.corrupt");
+    tokio::fs::rename(&config_path, &backup_path)
+        .await
+        .with_context(|| format!("Failed to back up app.json for {}", app_name))?;
+    Ok(backup_path)
+}
+
+pub(crate) fn has_app_config_json(app_name: &str) -> bool {
+    get_app_config_json_path(app_name).is_file()
+}
+
+/// The apps added with `add_app`. Launchers before the registry kept every app dir with an
+/// app.json, so without the file those dirs, except the embedded app's, are registered once.
+pub(crate) fn read_registered_app_names(embedded_app_name: &str) -> BTreeSet<String> {
+    let registry_path = path::get_registered_apps_path();
+    match fs::read_to_string(&registry_path) {
+        Ok(contents) => match serde_json::from_str(&contents) {
+            Ok(names) => return names,
+            Err(e) => warn!(
+                "Failed to parse {}, rebuilding it from the app dirs: {}",
+                registry_path.display(),
+                e
+            ),
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => {
+            warn!("Failed to read {}: {}", registry_path.display(), e);
+            return BTreeSet::new();
+        }
+    }
+    let names = discover_app_dirs_with_config(&path::get_apps_dir(), embedded_app_name);
+    info!("Registering existing apps {:?}", names);
+    if let Err(e) = write_registered_app_names(&names) {
+        warn!("{:#}", e);
+    }
+    names
+}
+
+fn discover_app_dirs_with_config(apps_dir: &Path, embedded_app_name: &str) -> BTreeSet<String> {
+    fs::read_dir(apps_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().join("app.json").is_file())
+                .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                .filter(|name| name != embedded_app_name)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn write_registered_app_names(names: &BTreeSet<String>) -> anyhow::Result<()> {
+    let registry_path = path::get_registered_apps_path();
+    if let Some(parent) = registry_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let contents = serde_json::to_string_pretty(names)?;
+    fs::write(&registry_path, contents)
+        .with_context(|| format!("Failed to write {}", registry_path.display()))
+}
+
+pub(crate) fn set_app_registered(
+    embedded_app_name: &str,
+    app_name: &str,
+    registered: bool,
+) -> anyhow::Result<()> {
+    let mut names = read_registered_app_names(embedded_app_name);
+    let changed = if registered {
+        names.insert(app_name.to_string())
+    } else {
+        names.remove(app_name)
+    };
+    if changed {
+        write_registered_app_names(&names)?;
+    }
+    Ok(())
+}
+
+/// Upgrades an app.json written by an older launcher one schema version at a time. Returns whether
+/// anything changed, in which case the caller rewrites the file.
+fn migrate_app_json(value: &mut serde_json::Value) -> bool {
+    let Some(object) = value.as_object_mut() else {
+        return false;
+    };
+    let version = object
+        .get("schema_version")
+        .and_then(|version| version.as_u64())
+        .unwrap_or(0);
+    if version >= u64::from(APP_SCHEMA_VERSION) {
+        return false;
+    }
+    // Steps upgrading `object` from one version to the next go here, oldest first. Version 0
+    // predates schema_version and already has the version 1 layout.
+    object.insert("schema_version".to_string(), APP_SCHEMA_VERSION.into());
+    true
+}
+
 pub(crate) async fn load_app_config_from_json(app_name: &str) -> anyhow::Result<Option<App>> {
     let config_path = get_app_config_json_path(app_name);
     if !config_path.exists() {
@@ -242,8 +901,12 @@ pub(crate) async fn load_app_config_from_json(app_name: &str) -> anyhow::Result<
         .await
         .with_context(|| format!("Failed to read app.json for {}", app_name))?;
 
-    match serde_json::from_str::<App>(&json_data) {
-        Ok(mut app) => {
+    let parsed = serde_json::from_str::<serde_json::Value>(&json_data).and_then(|mut value| {
+        let migrated = migrate_app_json(&mut value);
+        serde_json::from_value::<App>(value).map(|app| (app, migrated))
+    });
+    match parsed {
+        Ok((mut app, migrated)) => {
             if app.name != app_name {
                 warn!("App name mismatch in app.json ('{}') and directory ('{}'). Correcting to directory name: '{}'.", app.name, app_name, app_name);
                 app.name = app_name.to_string();
@@ -263,6 +926,15 @@ pub(crate) async fn load_app_config_from_json(app_name: &str) -> anyhow::Result<
                 }
             }
 
+            if migrated {
+                info!(
+                    "Migrated app.json for {} to schema version {}",
+                    app_name, APP_SCHEMA_VERSION
+                );
+                backup_app_config(app_name);
+                save_app_config_to_json(&app).await?;
+                app.schema_version = APP_SCHEMA_VERSION;
+            }
             Ok(Some(app))
         }
         Err(e) => {
@@ -280,3 +952,177 @@ pub(crate) async fn load_app_config_from_json(app_name: &str) -> anyhow::Result<
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        discover_app_dirs_with_config, migrate_app_json, parse_local_or_bundled_yml,
+        to_persisted_json, unsupported_python_version_warning, App, InstallState, Profile,
+        APP_SCHEMA_VERSION, BUNDLED_YML, RUNTIME_ONLY_FIELDS,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn migrates_app_json_without_schema_version() {
+        let mut value = json!({"name": "sample", "installed": true});
+        assert!(migrate_app_json(&mut value));
+        let app: App = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(app.schema_version, APP_SCHEMA_VERSION);
+        assert!(app.installed);
+
+        assert!(!migrate_app_json(&mut value));
+    }
+
+    #[test]
+    fn keeps_runtime_only_fields_out_of_app_json() {
+        let mut app: App = serde_json::from_value(json!({"name": "sample"})).unwrap();
+        app.nearest_surviving_version = Some("v1.0.0".to_string());
+        app.python_version_warning = Some("unsupported".to_string());
+
+        let emitted = serde_json::to_value(&app).unwrap();
+        let persisted = to_persisted_json(&app).unwrap();
+
+        for field in RUNTIME_ONLY_FIELDS {
+            assert!(emitted.get(*field).is_some(), "{} is not emitted", field);
+            assert!(persisted.get(*field).is_none(), "{} is persisted", field);
+        }
+        assert_eq!(persisted["schema_version"], json!(APP_SCHEMA_VERSION));
+    }
+
+    #[test]
+    fn reads_requirements_as_a_string_or_a_list() {
+        let single: Profile =
+            serde_json::from_value(json!({"name": "default", "requirements": "requirements.txt"}))
+                .unwrap();
+        assert_eq!(single.requirements, vec!["requirements.txt"]);
+
+        let many: Profile = serde_json::from_value(
+            json!({"name": "default", "requirements": ["requirements/base.txt", "", "requirements/gui.txt"]}),
+        )
+        .unwrap();
+        assert_eq!(
+            many.requirements,
+            vec!["requirements/base.txt", "requirements/gui.txt"]
+        );
+
+        let empty: Profile =
+            serde_json::from_value(json!({"name": "default", "requirements": ""})).unwrap();
+        assert!(empty.requirements.is_empty());
+    }
+
+    #[test]
+    fn derives_install_state_from_what_is_on_disk() {
+        assert_eq!(
+            InstallState::derive(false, false, false, false),
+            InstallState::NotInstalled
+        );
+        assert_eq!(
+            InstallState::derive(true, false, true, true),
+            InstallState::NotInstalled
+        );
+        assert_eq!(
+            InstallState::derive(false, true, false, false),
+            InstallState::RepoOnly
+        );
+        assert_eq!(
+            InstallState::derive(true, true, true, false),
+            InstallState::EnvMissing
+        );
+        assert_eq!(
+            InstallState::derive(false, true, true, true),
+            InstallState::EnvMissing
+        );
+        assert_eq!(
+            InstallState::derive(true, true, true, true),
+            InstallState::Installed
+        );
+    }
+
+    #[test]
+    fn falls_back_to_the_bundled_yml_when_the_local_one_is_malformed() {
+        let bundled: App = serde_yaml::from_str(BUNDLED_YML).unwrap();
+        let app = parse_local_or_bundled_yml(Some("name: [unclosed".to_string())).unwrap();
+        assert_eq!(app.name, bundled.name);
+        assert_eq!(parse_local_or_bundled_yml(None).unwrap().name, bundled.name);
+    }
+
+    #[test]
+    fn registers_existing_app_dirs_except_the_embedded_app() {
+        let temp = tempfile::tempdir().unwrap();
+        for (dir, has_config) in [("embedded", true), ("added", true), ("stale", false)] {
+            std::fs::create_dir_all(temp.path().join(dir)).unwrap();
+            if has_config {
+                std::fs::write(temp.path().join(dir).join("app.json"), "{}").unwrap();
+            }
+        }
+        assert_eq!(
+            discover_app_dirs_with_config(temp.path(), "embedded"),
+            ["added".to_string()].into_iter().collect()
+        );
+        assert!(discover_app_dirs_with_config(&temp.path().join("missing"), "embedded").is_empty());
+    }
+
+    #[test]
+    fn warns_about_unsupported_versions_and_unsatisfiable_ranges() {
+        let profiles: Vec<Profile> = serde_json::from_value(json!([
+            {"name": "pinned", "requires_python": "3.12"},
+            {"name": "range", "requires_python": ">=3.9,<3.13"},
+            {"name": "old", "requires_python": "3.6"},
+            {"name": "future", "requires_python": ">=4.0"}
+        ]))
+        .unwrap();
+        assert_eq!(unsupported_python_version_warning(&profiles[..2]), None);
+        let warning = unsupported_python_version_warning(&profiles).unwrap();
+        assert!(warning.contains("old (3.6), the default Python version will be used"));
+        assert!(warning.contains("future (>=4.0), setup will fail"));
+    }
+
+    #[test]
+    fn preserves_only_paths_inside_the_working_dir() {
+        let profile: Profile = serde_json::from_value(json!({
+            "name": "default",
+            "preserve_paths": ["saves", "config/user.json", "../outside", "", "/etc"]
+        }))
+        .unwrap();
+        assert_eq!(
+            profile.preserved_paths(),
+            vec![
+                std::path::PathBuf::from("saves"),
+                std::path::PathBuf::from("config/user.json")
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_cwd_only_inside_the_working_dir() {
+        let working_dir = std::path::Path::new("apps").join("demo").join("working");
+        for (cwd, expected) in [
+            ("", working_dir.clone()),
+            ("src", working_dir.join("src")),
+            ("./src/app", working_dir.join("./src/app")),
+            ("../outside", working_dir.clone()),
+            ("src/../../outside", working_dir.clone()),
+            ("/etc", working_dir.clone()),
+        ] {
+            let profile: Profile =
+                serde_json::from_value(json!({"name": "default", "cwd": cwd})).unwrap();
+            assert_eq!(profile.resolve_cwd(&working_dir), expected, "{}", cwd);
+        }
+    }
+
+    #[test]
+    fn defaults_to_unbuffered_utf8_output() {
+        let default: Profile = serde_json::from_value(json!({"name": "default"})).unwrap();
+        assert!(default.python_unbuffered());
+        assert_eq!(default.io_encoding(), Some("utf-8"));
+
+        let opted_out: Profile = serde_json::from_value(json!({
+            "name": "buffered",
+            "python_unbuffered": false,
+            "io_encoding": ""
+        }))
+        .unwrap();
+        assert!(!opted_out.python_unbuffered());
+        assert_eq!(opted_out.io_encoding(), None);
+    }
+}
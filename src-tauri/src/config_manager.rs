@@ -1,6 +1,7 @@
 // src/config_manager.rs
 use crate::python_env::get_supported_python_versions;
 use crate::utils::error::Error;
+use crate::utils::file::backup_file;
 use crate::utils::path::get_config_dir;
 use crate::utils::path::get_pip_cache_dir;
 use once_cell::sync::OnceCell;
@@ -8,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::env;
 use std::fs;
+use std::net::IpAddr;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use tauri::Manager;
@@ -17,21 +19,103 @@ const PIP_CACHE_DIR_CONFIG_KEY: &str = "Pip Cache Directory";
 const PIP_CACHE_DIR_OPTION_APP_INSTALL: &str = "App Install Directory";
 const PIP_CACHE_DIR_OPTION_SYSTEM_DEFAULT: &str = "System Default";
 const DEFAULT_PYTHON_VERSION_CONFIG_KEY: &str = "Default Python Version";
+const DEFAULT_PYTHON_VERSION: &str = "3.12";
 
 const PIP_INDEX_URL_CONFIG_KEY: &str = "Pip Index URL";
 const PIP_INDEX_URL_OPTION_SYSTEM_DEFAULT: &str = "";
-const PIP_INDEX_URL_OPTION_PYPI: &str = "https://pypi.org/simple/";
+pub const PIP_INDEX_URL_OPTION_PYPI: &str = "https://pypi.org/simple/";
 const PIP_INDEX_URL_OPTION_TSINGHUA: &str = "https://pypi.tuna.tsinghua.edu.cn/simple";
 const PIP_INDEX_URL_OPTION_ALIYUN: &str = "https://mirrors.aliyun.com/pypi/simple/";
 const PIP_INDEX_URL_OPTION_USTC: &str = "https://mirrors.ustc.edu.cn/pypi/simple/";
 const PIP_INDEX_URL_OPTION_HUAWEI: &str = "https://repo.huaweicloud.com/repository/pypi/simple/";
 const PIP_INDEX_URL_OPTION_TENCENT: &str = "https://mirrors.cloud.tencent.com/pypi/simple/";
 
+const PIP_EXTRA_ARGS_CONFIG_KEY: &str = "Pip Extra Args";
+const PIP_INDEX_USERNAME_CONFIG_KEY: &str = "Pip Index Username";
+const PIP_INDEX_PASSWORD_CONFIG_KEY: &str = "Pip Index Password";
+
 const UPDATE_METHOD_CONFIG_KEY: &str = "Update Method";
 pub const UPDATE_METHOD_OPTION_MANUAL: &str = "MANUAL_UPDATE";
 pub const UPDATE_METHOD_OPTION_AUTO: &str = "AUTO_UPDATE";
 pub const UPDATE_METHOD_OPTION_IGNORE: &str = "IGNORE_UPDATE";
 
+const AUTO_START_CONFIG_KEY: &str = "Auto Start";
+pub const AUTO_START_OPTION_OFF: &str = "AUTO_START_OFF";
+pub const AUTO_START_OPTION_IF_INSTALLED: &str = "AUTO_START_IF_INSTALLED";
+pub const AUTO_START_OPTION_IF_LATEST: &str = "AUTO_START_IF_LATEST";
+
+const MISSING_PYTHON_CONFIG_KEY: &str = "Missing Python";
+const MISSING_PYTHON_OPTION_REPAIR: &str = "MISSING_PYTHON_REPAIR";
+const MISSING_PYTHON_OPTION_RESET: &str = "MISSING_PYTHON_RESET";
+
+const STOP_APPS_ON_EXIT_CONFIG_KEY: &str = "Stop Apps On Exit";
+const STOP_APPS_ON_EXIT_OPTION_OFF: &str = "STOP_APPS_ON_EXIT_OFF";
+const STOP_APPS_ON_EXIT_OPTION_ON: &str = "STOP_APPS_ON_EXIT_ON";
+
+const SLOW_DRIVE_WARNING_CONFIG_KEY: &str = "Slow Drive Warning";
+const SLOW_DRIVE_WARNING_OPTION_ON: &str = "SLOW_DRIVE_WARNING_ON";
+const SLOW_DRIVE_WARNING_OPTION_OFF: &str = "SLOW_DRIVE_WARNING_OFF";
+
+const SECOND_LAUNCH_CONFIG_KEY: &str = "Second Launch";
+pub const SECOND_LAUNCH_OPTION_FOCUS_WINDOW: &str = "SECOND_LAUNCH_FOCUS_WINDOW";
+pub const SECOND_LAUNCH_OPTION_FORWARD_COMMAND: &str = "SECOND_LAUNCH_FORWARD_COMMAND";
+pub const SECOND_LAUNCH_OPTION_SILENT: &str = "SECOND_LAUNCH_SILENT";
+
+const PYTHON_DOWNLOAD_MIRROR_CONFIG_KEY: &str = "Python Download Mirror";
+pub const PYTHON_DOWNLOAD_MIRROR_OPTION_AUTO: &str = "PYTHON_MIRROR_AUTO";
+pub const PYTHON_DOWNLOAD_MIRROR_OPTION_OFFICIAL: &str = "PYTHON_MIRROR_OFFICIAL";
+pub const PYTHON_DOWNLOAD_MIRROR_OPTION_CHINA: &str = "PYTHON_MIRROR_CHINA";
+
+const NETWORK_REGION_CONFIG_KEY: &str = "Network Region";
+pub const NETWORK_REGION_OPTION_CHINA: &str = "NETWORK_REGION_CHINA";
+const NETWORK_REGION_OPTION_GLOBAL: &str = "NETWORK_REGION_GLOBAL";
+const NETWORK_REGION_OPTION_CUSTOM: &str = "NETWORK_REGION_CUSTOM";
+
+/// Folded into 'Network Region'; only read to migrate old config files.
+const LEGACY_DOWNLOAD_REGION_CONFIG_KEY: &str = "Download Region";
+const LEGACY_DOWNLOAD_REGION_OPTION_CHINA: &str = "DOWNLOAD_REGION_CHINA";
+const LEGACY_DOWNLOAD_REGION_OPTION_GLOBAL: &str = "DOWNLOAD_REGION_GLOBAL";
+
+const GIT_PROXY_CONFIG_KEY: &str = "Git Proxy";
+const GIT_PROXY_OPTION_AUTO: &str = "GIT_PROXY_AUTO";
+const GIT_PROXY_OPTION_OFF: &str = "GIT_PROXY_OFF";
+
+const NETWORK_RETRIES_CONFIG_KEY: &str = "Network Retries";
+const DEFAULT_NETWORK_RETRIES: i32 = 2;
+
+const STATUS_UPDATE_INTERVAL_CONFIG_KEY: &str = "Status Update Interval";
+const DEFAULT_STATUS_UPDATE_INTERVAL_SECS: i32 = 2;
+
+const MAX_CONCURRENT_INSTALLS_CONFIG_KEY: &str = "Max Concurrent Installs";
+const DEFAULT_MAX_CONCURRENT_INSTALLS: i32 = 2;
+
+const CONFIG_BACKUPS_CONFIG_KEY: &str = "Config Backups";
+const DEFAULT_CONFIG_BACKUPS: i32 = 5;
+
+const SOURCE_ADDRESS_CONFIG_KEY: &str = "Source Address";
+
+const PROXY_CONFIG_KEY: &str = "Proxy";
+
+const APP_SORT_ORDER_CONFIG_KEY: &str = "App Sort Order";
+pub const APP_SORT_ORDER_OPTION_RUNNING_FIRST: &str = "APP_SORT_ORDER_RUNNING_FIRST";
+pub const APP_SORT_ORDER_OPTION_ALPHABETICAL: &str = "APP_SORT_ORDER_ALPHABETICAL";
+pub const APP_SORT_ORDER_OPTION_LAST_START: &str = "APP_SORT_ORDER_LAST_START";
+pub const APP_SORT_ORDER_OPTION_INSTALL_DATE: &str = "APP_SORT_ORDER_INSTALL_DATE";
+
+const FETCH_ON_STARTUP_CONFIG_KEY: &str = "Fetch On Startup";
+pub const FETCH_ON_STARTUP_OPTION_ALWAYS: &str = "FETCH_ON_STARTUP_ALWAYS";
+pub const FETCH_ON_STARTUP_OPTION_INTERVAL: &str = "FETCH_ON_STARTUP_INTERVAL";
+pub const FETCH_ON_STARTUP_OPTION_NEVER: &str = "FETCH_ON_STARTUP_NEVER";
+
+const SUPPRESSED_OUTPUT_CONFIG_KEY: &str = "Suppressed Output";
+const DEFAULT_SUPPRESSED_OUTPUT: &str = "A new release of pip is available;[notice] To update, run";
+
+const PIP_VERBOSITY_CONFIG_KEY: &str = "Pip Verbosity";
+pub const PIP_VERBOSITY_OPTION_QUIET: &str = "PIP_VERBOSITY_QUIET";
+pub const PIP_VERBOSITY_OPTION_NORMAL: &str = "PIP_VERBOSITY_NORMAL";
+pub const PIP_VERBOSITY_OPTION_VERBOSE: &str = "PIP_VERBOSITY_VERBOSE";
+pub const PIP_VERBOSITY_OPTION_VERY_VERBOSE: &str = "PIP_VERBOSITY_VERY_VERBOSE";
+
 const I18N_CONFIG_KEY: &str = "Language";
 const I18N_OPTION_EN: &str = "en";
 const I18N_OPTION_ZH_CN: &str = "zh-CN";
@@ -97,6 +181,15 @@ pub struct AppConfig {
     config_path: PathBuf,
 }
 
+/// The region a system locale implies for the automatic choices of a custom 'Network Region'.
+fn network_region_from_locale(locale: &str) -> &'static str {
+    if locale == "zh-CN" {
+        NETWORK_REGION_OPTION_CHINA
+    } else {
+        NETWORK_REGION_OPTION_GLOBAL
+    }
+}
+
 fn get_default_lang_from_locale() -> &'static str {
     let locale = get_default_locale();
     if locale == "zh-CN" {
@@ -114,6 +207,54 @@ fn get_default_lang_from_locale() -> &'static str {
     }
 }
 
+/// Turns an explicit 'Download Region' from an older config into the matching 'Network Region'
+/// preset. It only decided the pip index and the Python mirror while both were left on their
+/// defaults, so it is dropped when either was changed.
+fn migrate_download_region(loaded_values: &mut HashMap<String, ConfigValue>) {
+    let Some(ConfigValue::String(download_region)) =
+        loaded_values.remove(LEGACY_DOWNLOAD_REGION_CONFIG_KEY)
+    else {
+        return;
+    };
+    let preset = match download_region.as_str() {
+        LEGACY_DOWNLOAD_REGION_OPTION_CHINA => NETWORK_REGION_OPTION_CHINA,
+        LEGACY_DOWNLOAD_REGION_OPTION_GLOBAL => NETWORK_REGION_OPTION_GLOBAL,
+        _ => return,
+    };
+    let is_unset_or = |key: &str, default: &str| match loaded_values.get(key) {
+        None => true,
+        Some(ConfigValue::String(value)) => value == default,
+        Some(_) => false,
+    };
+    let locale_pip_index_url =
+        if network_region_from_locale(&get_default_locale()) == NETWORK_REGION_OPTION_CHINA {
+            PIP_INDEX_URL_OPTION_ALIYUN
+        } else {
+            PIP_INDEX_URL_OPTION_SYSTEM_DEFAULT
+        };
+    if is_unset_or(NETWORK_REGION_CONFIG_KEY, NETWORK_REGION_OPTION_CUSTOM)
+        && is_unset_or(PIP_INDEX_URL_CONFIG_KEY, locale_pip_index_url)
+        && is_unset_or(
+            PYTHON_DOWNLOAD_MIRROR_CONFIG_KEY,
+            PYTHON_DOWNLOAD_MIRROR_OPTION_AUTO,
+        )
+    {
+        info!(
+            "Migrating '{}' {} to '{}' {}",
+            LEGACY_DOWNLOAD_REGION_CONFIG_KEY, download_region, NETWORK_REGION_CONFIG_KEY, preset
+        );
+        loaded_values.insert(
+            NETWORK_REGION_CONFIG_KEY.to_string(),
+            ConfigValue::String(preset.to_string()),
+        );
+    } else {
+        info!(
+            "Dropping '{}' {}, the network settings it applied to were changed.",
+            LEGACY_DOWNLOAD_REGION_CONFIG_KEY, download_region
+        );
+    }
+}
+
 impl AppConfig {
     pub fn new() -> Self {
         let config_dir = get_config_dir();
@@ -175,13 +316,13 @@ impl AppConfig {
             .map(ConfigValue::String)
             .collect();
 
-        let default_python_version_str = "3.12".to_string();
+        let default_python_version_str = DEFAULT_PYTHON_VERSION.to_string();
 
         items.insert(
             DEFAULT_PYTHON_VERSION_CONFIG_KEY.to_string(),
             ConfigItem {
                 name: DEFAULT_PYTHON_VERSION_CONFIG_KEY.to_string(),
-                description: "The default Python version to be used.".to_string(),
+                description: "The Python version installed for profiles whose requires_python is empty or unsupported.".to_string(),
                 value: ConfigValue::String(default_python_version_str.clone()),
                 default_value: ConfigValue::String(default_python_version_str),
                 options: if python_version_options.is_empty() {
@@ -194,7 +335,8 @@ impl AppConfig {
 
         let locale = get_default_locale();
         info!("System locale is: {}", locale);
-        let default_pip_url = if locale == "zh_CN" {
+        let default_pip_url = if network_region_from_locale(&locale) == NETWORK_REGION_OPTION_CHINA
+        {
             PIP_INDEX_URL_OPTION_ALIYUN.to_string()
         } else {
             PIP_INDEX_URL_OPTION_SYSTEM_DEFAULT.to_string()
@@ -219,6 +361,66 @@ impl AppConfig {
             },
         );
 
+        items.insert(
+            PIP_EXTRA_ARGS_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: PIP_EXTRA_ARGS_CONFIG_KEY.to_string(),
+                description: "Extra arguments appended to every pip install, in addition to the profile's pip_args (e.g. '--require-hashes'). An '--index-url'/'-i' here replaces the 'Pip Index URL' setting. A '--proxy' here only applies to pip; use the 'Proxy' setting for every download.".to_string(),
+                value: ConfigValue::String(String::new()),
+                default_value: ConfigValue::String(String::new()),
+                options: None,
+            },
+        );
+
+        items.insert(
+            PIP_INDEX_USERNAME_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: PIP_INDEX_USERNAME_CONFIG_KEY.to_string(),
+                description: "Username for a private package index (Artifactory, Nexus, devpi...). Applied to the index URL in effect, from 'Pip Index URL' or an '--index-url' in the pip args. Empty disables authentication.".to_string(),
+                value: ConfigValue::String(String::new()),
+                default_value: ConfigValue::String(String::new()),
+                options: None,
+            },
+        );
+
+        items.insert(
+            PIP_INDEX_PASSWORD_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: PIP_INDEX_PASSWORD_CONFIG_KEY.to_string(),
+                description: "Password or API token for the private package index. It is handed to pip through the environment, never on the command line, but is stored unencrypted in the config file.".to_string(),
+                value: ConfigValue::String(String::new()),
+                default_value: ConfigValue::String(String::new()),
+                options: None,
+            },
+        );
+
+        items.insert(
+            PIP_VERBOSITY_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: PIP_VERBOSITY_CONFIG_KEY.to_string(),
+                description: "How much pip prints while installing requirements. 'PIP_VERBOSITY_QUIET' passes -q, 'PIP_VERBOSITY_VERBOSE' -v and 'PIP_VERBOSITY_VERY_VERBOSE' -vv; the verbose levels also show error lines that 'Suppressed Output' would hide.".to_string(),
+                value: ConfigValue::String(PIP_VERBOSITY_OPTION_NORMAL.to_string()),
+                default_value: ConfigValue::String(PIP_VERBOSITY_OPTION_NORMAL.to_string()),
+                options: Some(vec![
+                    ConfigValue::String(PIP_VERBOSITY_OPTION_QUIET.to_string()),
+                    ConfigValue::String(PIP_VERBOSITY_OPTION_NORMAL.to_string()),
+                    ConfigValue::String(PIP_VERBOSITY_OPTION_VERBOSE.to_string()),
+                    ConfigValue::String(PIP_VERBOSITY_OPTION_VERY_VERBOSE.to_string()),
+                ]),
+            },
+        );
+
+        items.insert(
+            SUPPRESSED_OUTPUT_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: SUPPRESSED_OUTPUT_CONFIG_KEY.to_string(),
+                description: "';'-separated texts; stderr lines of pip and the app containing any of them are only written to the log file, not shown. While 'Pip Verbosity' is verbose, lines that look like errors are shown anyway.".to_string(),
+                value: ConfigValue::String(DEFAULT_SUPPRESSED_OUTPUT.to_string()),
+                default_value: ConfigValue::String(DEFAULT_SUPPRESSED_OUTPUT.to_string()),
+                options: None,
+            },
+        );
+
         items.insert(
             UPDATE_METHOD_CONFIG_KEY.to_string(),
             ConfigItem {
@@ -234,6 +436,242 @@ impl AppConfig {
             },
         );
 
+        items.insert(
+            AUTO_START_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: AUTO_START_CONFIG_KEY.to_string(),
+                description: "Whether the app is started when pyappify opens. 'AUTO_START_IF_LATEST' starts it only when it is on the latest release (after any auto update), 'AUTO_START_IF_INSTALLED' starts any installed version, and 'AUTO_START_OFF' never starts it.".to_string(),
+                value: ConfigValue::String(AUTO_START_OPTION_IF_LATEST.to_string()),
+                default_value: ConfigValue::String(AUTO_START_OPTION_IF_LATEST.to_string()),
+                options: Some(vec![
+                    ConfigValue::String(AUTO_START_OPTION_OFF.to_string()),
+                    ConfigValue::String(AUTO_START_OPTION_IF_INSTALLED.to_string()),
+                    ConfigValue::String(AUTO_START_OPTION_IF_LATEST.to_string()),
+                ]),
+            },
+        );
+
+        items.insert(
+            APP_SORT_ORDER_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: APP_SORT_ORDER_CONFIG_KEY.to_string(),
+                description: "Order of the app list. 'APP_SORT_ORDER_RUNNING_FIRST' lists running apps first, then the most recently started; the others sort by name, by last start or by install date, newest first.".to_string(),
+                value: ConfigValue::String(APP_SORT_ORDER_OPTION_RUNNING_FIRST.to_string()),
+                default_value: ConfigValue::String(APP_SORT_ORDER_OPTION_RUNNING_FIRST.to_string()),
+                options: Some(vec![
+                    ConfigValue::String(APP_SORT_ORDER_OPTION_RUNNING_FIRST.to_string()),
+                    ConfigValue::String(APP_SORT_ORDER_OPTION_ALPHABETICAL.to_string()),
+                    ConfigValue::String(APP_SORT_ORDER_OPTION_LAST_START.to_string()),
+                    ConfigValue::String(APP_SORT_ORDER_OPTION_INSTALL_DATE.to_string()),
+                ]),
+            },
+        );
+
+        items.insert(
+            FETCH_ON_STARTUP_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: FETCH_ON_STARTUP_CONFIG_KEY.to_string(),
+                description: "Whether installed apps fetch their tags from git when pyappify opens. 'FETCH_ON_STARTUP_INTERVAL' only fetches when the last fetch is older than a day, 'FETCH_ON_STARTUP_NEVER' leaves it to 'Check for updates'.".to_string(),
+                value: ConfigValue::String(FETCH_ON_STARTUP_OPTION_ALWAYS.to_string()),
+                default_value: ConfigValue::String(FETCH_ON_STARTUP_OPTION_ALWAYS.to_string()),
+                options: Some(vec![
+                    ConfigValue::String(FETCH_ON_STARTUP_OPTION_ALWAYS.to_string()),
+                    ConfigValue::String(FETCH_ON_STARTUP_OPTION_INTERVAL.to_string()),
+                    ConfigValue::String(FETCH_ON_STARTUP_OPTION_NEVER.to_string()),
+                ]),
+            },
+        );
+
+        items.insert(
+            PYTHON_DOWNLOAD_MIRROR_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: PYTHON_DOWNLOAD_MIRROR_CONFIG_KEY.to_string(),
+                description: "Where Python builds are downloaded from. 'PYTHON_MIRROR_OFFICIAL' uses python.org and GitHub releases, 'PYTHON_MIRROR_CHINA' uses the Huawei Cloud and ModelScope mirrors of the same archives, and 'PYTHON_MIRROR_AUTO' picks the China mirror when the system locale is zh-CN. Only these two sources are offered, so outside China the official one is used. The other source is always tried as a fallback.".to_string(),
+                value: ConfigValue::String(PYTHON_DOWNLOAD_MIRROR_OPTION_AUTO.to_string()),
+                default_value: ConfigValue::String(PYTHON_DOWNLOAD_MIRROR_OPTION_AUTO.to_string()),
+                options: Some(vec![
+                    ConfigValue::String(PYTHON_DOWNLOAD_MIRROR_OPTION_AUTO.to_string()),
+                    ConfigValue::String(PYTHON_DOWNLOAD_MIRROR_OPTION_OFFICIAL.to_string()),
+                    ConfigValue::String(PYTHON_DOWNLOAD_MIRROR_OPTION_CHINA.to_string()),
+                ]),
+            },
+        );
+
+        items.insert(
+            NETWORK_REGION_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: NETWORK_REGION_CONFIG_KEY.to_string(),
+                description: "Presets the pip index, the Python download mirror and the git proxy together. 'NETWORK_REGION_CHINA' uses the Aliyun pip mirror and the China Python mirror, 'NETWORK_REGION_GLOBAL' uses PyPI and the official Python downloads, both auto-detect the git proxy. 'NETWORK_REGION_CUSTOM' uses the 'Pip Index URL', 'Python Download Mirror' and 'Git Proxy' settings. The region is independent of the UI language.".to_string(),
+                value: ConfigValue::String(NETWORK_REGION_OPTION_CUSTOM.to_string()),
+                default_value: ConfigValue::String(NETWORK_REGION_OPTION_CUSTOM.to_string()),
+                options: Some(vec![
+                    ConfigValue::String(NETWORK_REGION_OPTION_CHINA.to_string()),
+                    ConfigValue::String(NETWORK_REGION_OPTION_GLOBAL.to_string()),
+                    ConfigValue::String(NETWORK_REGION_OPTION_CUSTOM.to_string()),
+                ]),
+            },
+        );
+
+        items.insert(
+            GIT_PROXY_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: GIT_PROXY_CONFIG_KEY.to_string(),
+                description: "Whether git clone/fetch uses the proxy from the git config and the http_proxy/https_proxy environment variables. Only used when 'Network Region' is 'NETWORK_REGION_CUSTOM'.".to_string(),
+                value: ConfigValue::String(GIT_PROXY_OPTION_AUTO.to_string()),
+                default_value: ConfigValue::String(GIT_PROXY_OPTION_AUTO.to_string()),
+                options: Some(vec![
+                    ConfigValue::String(GIT_PROXY_OPTION_AUTO.to_string()),
+                    ConfigValue::String(GIT_PROXY_OPTION_OFF.to_string()),
+                ]),
+            },
+        );
+
+        items.insert(
+            NETWORK_RETRIES_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: NETWORK_RETRIES_CONFIG_KEY.to_string(),
+                description: "How many times a Python download or a git clone/fetch is retried after a transient network error. Authentication and not-found errors are never retried.".to_string(),
+                value: ConfigValue::Integer(DEFAULT_NETWORK_RETRIES),
+                default_value: ConfigValue::Integer(DEFAULT_NETWORK_RETRIES),
+                options: Some(vec![
+                    ConfigValue::Integer(0),
+                    ConfigValue::Integer(1),
+                    ConfigValue::Integer(2),
+                    ConfigValue::Integer(3),
+                    ConfigValue::Integer(5),
+                ]),
+            },
+        );
+
+        items.insert(
+            MISSING_PYTHON_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: MISSING_PYTHON_CONFIG_KEY.to_string(),
+                description: "What starting an app does when its Python is missing, e.g. quarantined by antivirus. 'MISSING_PYTHON_REPAIR' reinstalls only Python and the requirements, 'MISSING_PYTHON_RESET' deletes the whole app including its repository and working dir.".to_string(),
+                value: ConfigValue::String(MISSING_PYTHON_OPTION_REPAIR.to_string()),
+                default_value: ConfigValue::String(MISSING_PYTHON_OPTION_REPAIR.to_string()),
+                options: Some(vec![
+                    ConfigValue::String(MISSING_PYTHON_OPTION_REPAIR.to_string()),
+                    ConfigValue::String(MISSING_PYTHON_OPTION_RESET.to_string()),
+                ]),
+            },
+        );
+
+        items.insert(
+            STOP_APPS_ON_EXIT_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: STOP_APPS_ON_EXIT_CONFIG_KEY.to_string(),
+                description: "Whether quitting pyappify stops the apps it started. When off, running apps keep running after pyappify closes.".to_string(),
+                value: ConfigValue::String(STOP_APPS_ON_EXIT_OPTION_OFF.to_string()),
+                default_value: ConfigValue::String(STOP_APPS_ON_EXIT_OPTION_OFF.to_string()),
+                options: Some(vec![
+                    ConfigValue::String(STOP_APPS_ON_EXIT_OPTION_OFF.to_string()),
+                    ConfigValue::String(STOP_APPS_ON_EXIT_OPTION_ON.to_string()),
+                ]),
+            },
+        );
+
+        items.insert(
+            SLOW_DRIVE_WARNING_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: SLOW_DRIVE_WARNING_CONFIG_KEY.to_string(),
+                description: "Whether pyappify warns once at startup when an app is installed on a network drive or a removable drive, where apps start slowly and process matching by path is unreliable.".to_string(),
+                value: ConfigValue::String(SLOW_DRIVE_WARNING_OPTION_ON.to_string()),
+                default_value: ConfigValue::String(SLOW_DRIVE_WARNING_OPTION_ON.to_string()),
+                options: Some(vec![
+                    ConfigValue::String(SLOW_DRIVE_WARNING_OPTION_ON.to_string()),
+                    ConfigValue::String(SLOW_DRIVE_WARNING_OPTION_OFF.to_string()),
+                ]),
+            },
+        );
+
+        items.insert(
+            SECOND_LAUNCH_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: SECOND_LAUNCH_CONFIG_KEY.to_string(),
+                description: "What happens when pyappify is launched while it is already running, e.g. from the startup shortcut. 'SECOND_LAUNCH_FOCUS_WINDOW' shows the window; 'SECOND_LAUNCH_FORWARD_COMMAND' starts the app a `-c start` launch names and shows the window otherwise; 'SECOND_LAUNCH_SILENT' starts the named app without showing anything and ignores other launches.".to_string(),
+                value: ConfigValue::String(SECOND_LAUNCH_OPTION_FOCUS_WINDOW.to_string()),
+                default_value: ConfigValue::String(SECOND_LAUNCH_OPTION_FOCUS_WINDOW.to_string()),
+                options: Some(vec![
+                    ConfigValue::String(SECOND_LAUNCH_OPTION_FOCUS_WINDOW.to_string()),
+                    ConfigValue::String(SECOND_LAUNCH_OPTION_FORWARD_COMMAND.to_string()),
+                    ConfigValue::String(SECOND_LAUNCH_OPTION_SILENT.to_string()),
+                ]),
+            },
+        );
+
+        items.insert(
+            STATUS_UPDATE_INTERVAL_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: STATUS_UPDATE_INTERVAL_CONFIG_KEY.to_string(),
+                description: "Seconds between the scans that detect whether apps are running. Longer intervals lower the background CPU use when many processes are running.".to_string(),
+                value: ConfigValue::Integer(DEFAULT_STATUS_UPDATE_INTERVAL_SECS),
+                default_value: ConfigValue::Integer(DEFAULT_STATUS_UPDATE_INTERVAL_SECS),
+                options: Some(vec![
+                    ConfigValue::Integer(1),
+                    ConfigValue::Integer(2),
+                    ConfigValue::Integer(5),
+                    ConfigValue::Integer(10),
+                    ConfigValue::Integer(30),
+                ]),
+            },
+        );
+
+        items.insert(
+            MAX_CONCURRENT_INSTALLS_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: MAX_CONCURRENT_INSTALLS_CONFIG_KEY.to_string(),
+                description: "How many apps may run pip install at the same time. Further installs wait for a free slot; git clones and Python downloads are not limited by it.".to_string(),
+                value: ConfigValue::Integer(DEFAULT_MAX_CONCURRENT_INSTALLS),
+                default_value: ConfigValue::Integer(DEFAULT_MAX_CONCURRENT_INSTALLS),
+                options: Some(vec![
+                    ConfigValue::Integer(1),
+                    ConfigValue::Integer(2),
+                    ConfigValue::Integer(3),
+                    ConfigValue::Integer(4),
+                ]),
+            },
+        );
+
+        items.insert(
+            CONFIG_BACKUPS_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: CONFIG_BACKUPS_CONFIG_KEY.to_string(),
+                description: "How many timestamped backups of each app.json and of app_config.json are kept in the 'backups' folder next to them. app.json is backed up before updates and migrations, app_config.json whenever it changes. 0 disables backups.".to_string(),
+                value: ConfigValue::Integer(DEFAULT_CONFIG_BACKUPS),
+                default_value: ConfigValue::Integer(DEFAULT_CONFIG_BACKUPS),
+                options: Some(vec![
+                    ConfigValue::Integer(0),
+                    ConfigValue::Integer(1),
+                    ConfigValue::Integer(3),
+                    ConfigValue::Integer(5),
+                    ConfigValue::Integer(10),
+                ]),
+            },
+        );
+
+        items.insert(
+            SOURCE_ADDRESS_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: SOURCE_ADDRESS_CONFIG_KEY.to_string(),
+                description: "Local IP address Python downloads are sent from, to route them through a specific network interface or VPN. Empty uses the OS default. Git operations always use the OS default route.".to_string(),
+                value: ConfigValue::String(String::new()),
+                default_value: ConfigValue::String(String::new()),
+                options: None,
+            },
+        );
+
+        items.insert(
+            PROXY_CONFIG_KEY.to_string(),
+            ConfigItem {
+                name: PROXY_CONFIG_KEY.to_string(),
+                description: "Proxy for pip, Python downloads and git, e.g. 'http://host:port' or 'socks5://host:port'. Git only supports http(s) proxies and connects directly, or as 'Git Proxy' allows, for any other. Empty uses the http(s)_proxy environment variables.".to_string(),
+                value: ConfigValue::String(String::new()),
+                default_value: ConfigValue::String(String::new()),
+                options: None,
+            },
+        );
+
         items
     }
 
@@ -279,7 +717,8 @@ impl AppConfig {
 
         match fs::read_to_string(&self.config_path) {
             Ok(content) => match serde_json::from_str::<HashMap<String, ConfigValue>>(&content) {
-                Ok(loaded_values) => {
+                Ok(mut loaded_values) => {
+                    migrate_download_region(&mut loaded_values);
                     for (name, loaded_value) in loaded_values {
                         if let Some(item) = self.items.get_mut(&name) {
                             item.value = loaded_value;
@@ -326,6 +765,15 @@ impl AppConfig {
                     }
                 }
 
+                let unchanged =
+                    fs::read_to_string(&self.config_path).is_ok_and(|existing| existing == content);
+                if unchanged {
+                    return;
+                }
+                if let Err(e) = backup_file(&self.config_path, self.get_effective_config_backups())
+                {
+                    warn!("Failed to back up {:?}: {:#}", self.config_path, e);
+                }
                 if let Err(e) = fs::write(&self.config_path, content) {
                     error!(
                         "Failed to write config file to {:?}: {}",
@@ -385,7 +833,7 @@ impl AppConfig {
 
                 if name == PIP_CACHE_DIR_CONFIG_KEY {
                     self.update_pip_cache_env_var_from_config();
-                } else if name == PIP_INDEX_URL_CONFIG_KEY {
+                } else if name == PIP_INDEX_URL_CONFIG_KEY || name == NETWORK_REGION_CONFIG_KEY {
                     self.update_pip_index_url_env_var_from_config();
                 }
                 info!("Updated config item '{}' and saved configuration.", name);
@@ -432,27 +880,14 @@ impl AppConfig {
     }
 
     fn update_pip_index_url_env_var_from_config(&self) {
-        match self.get_item_value(PIP_INDEX_URL_CONFIG_KEY) {
-            Some(ConfigValue::String(value)) => {
-                if value == PIP_INDEX_URL_OPTION_SYSTEM_DEFAULT || value.is_empty() {
-                    env::remove_var("PIP_INDEX_URL");
-                    info!("Unset PIP_INDEX_URL, using system default pip index.");
-                } else {
-                    env::set_var("PIP_INDEX_URL", &value);
-                    info!("Set PIP_INDEX_URL to: {}", value);
-                }
-            }
-            Some(_) => {
-                error!(
-                    "Config item '{}' is not a string. PIP_INDEX_URL not changed.",
-                    PIP_INDEX_URL_CONFIG_KEY
-                );
+        match self.get_effective_pip_index_url() {
+            Some(value) => {
+                env::set_var("PIP_INDEX_URL", &value);
+                info!("Set PIP_INDEX_URL to: {}", value);
             }
             None => {
-                warn!(
-                    "Config item '{}' not found. PIP_INDEX_URL not changed.",
-                    PIP_INDEX_URL_CONFIG_KEY
-                );
+                env::remove_var("PIP_INDEX_URL");
+                info!("Unset PIP_INDEX_URL, using system default pip index.");
             }
         }
     }
@@ -489,7 +924,32 @@ impl AppConfig {
         }
     }
 
+    pub fn get_effective_network_region(&self) -> &'static str {
+        match self.get_item_value(NETWORK_REGION_CONFIG_KEY) {
+            Some(ConfigValue::String(value)) => match value.as_str() {
+                NETWORK_REGION_OPTION_CHINA => NETWORK_REGION_OPTION_CHINA,
+                NETWORK_REGION_OPTION_GLOBAL => NETWORK_REGION_OPTION_GLOBAL,
+                _ => NETWORK_REGION_OPTION_CUSTOM,
+            },
+            _ => NETWORK_REGION_OPTION_CUSTOM,
+        }
+    }
+
+    /// China or Global, from the 'Network Region' preset, or from the system locale for a custom
+    /// region. The UI language never decides it.
+    pub fn get_effective_region(&self) -> &'static str {
+        match self.get_effective_network_region() {
+            NETWORK_REGION_OPTION_CUSTOM => network_region_from_locale(&get_default_locale()),
+            preset => preset,
+        }
+    }
+
     pub fn get_effective_pip_index_url(&self) -> Option<String> {
+        match self.get_effective_network_region() {
+            NETWORK_REGION_OPTION_CHINA => return Some(PIP_INDEX_URL_OPTION_ALIYUN.to_string()),
+            NETWORK_REGION_OPTION_GLOBAL => return Some(PIP_INDEX_URL_OPTION_PYPI.to_string()),
+            _ => {}
+        }
         match self.get_item_value(PIP_INDEX_URL_CONFIG_KEY) {
             Some(ConfigValue::String(value)) => {
                 if value == PIP_INDEX_URL_OPTION_SYSTEM_DEFAULT || value.is_empty() {
@@ -515,6 +975,55 @@ impl AppConfig {
         }
     }
 
+    pub fn get_effective_pip_extra_args(&self) -> Vec<String> {
+        match self.get_item_value(PIP_EXTRA_ARGS_CONFIG_KEY) {
+            Some(ConfigValue::String(value)) => {
+                value.split_whitespace().map(str::to_string).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// `(username, password)` for the pip index, `None` when no username is set.
+    pub fn get_effective_pip_index_credentials(&self) -> Option<(String, String)> {
+        let username = match self.get_item_value(PIP_INDEX_USERNAME_CONFIG_KEY) {
+            Some(ConfigValue::String(value)) if !value.trim().is_empty() => {
+                value.trim().to_string()
+            }
+            _ => return None,
+        };
+        let password = match self.get_item_value(PIP_INDEX_PASSWORD_CONFIG_KEY) {
+            Some(ConfigValue::String(value)) => value,
+            _ => String::new(),
+        };
+        Some((username, password))
+    }
+
+    pub fn get_effective_pip_verbosity(&self) -> &'static str {
+        match self.get_item_value(PIP_VERBOSITY_CONFIG_KEY) {
+            Some(ConfigValue::String(value)) => match value.as_str() {
+                PIP_VERBOSITY_OPTION_QUIET => PIP_VERBOSITY_OPTION_QUIET,
+                PIP_VERBOSITY_OPTION_VERBOSE => PIP_VERBOSITY_OPTION_VERBOSE,
+                PIP_VERBOSITY_OPTION_VERY_VERBOSE => PIP_VERBOSITY_OPTION_VERY_VERBOSE,
+                _ => PIP_VERBOSITY_OPTION_NORMAL,
+            },
+            _ => PIP_VERBOSITY_OPTION_NORMAL,
+        }
+    }
+
+    pub fn get_effective_suppressed_output(&self) -> Vec<String> {
+        let value = match self.get_item_value(SUPPRESSED_OUTPUT_CONFIG_KEY) {
+            Some(ConfigValue::String(value)) => value,
+            _ => DEFAULT_SUPPRESSED_OUTPUT.to_string(),
+        };
+        value
+            .split(';')
+            .map(str::trim)
+            .filter(|pattern| !pattern.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
     pub fn get_effective_update_method(&self) -> &str {
         match self.get_item_value(UPDATE_METHOD_CONFIG_KEY) {
             Some(ConfigValue::String(value)) => match value.as_str() {
@@ -526,6 +1035,164 @@ impl AppConfig {
         }
     }
 
+    pub fn get_effective_auto_start(&self) -> &'static str {
+        match self.get_item_value(AUTO_START_CONFIG_KEY) {
+            Some(ConfigValue::String(value)) => match value.as_str() {
+                AUTO_START_OPTION_OFF => AUTO_START_OPTION_OFF,
+                AUTO_START_OPTION_IF_INSTALLED => AUTO_START_OPTION_IF_INSTALLED,
+                _ => AUTO_START_OPTION_IF_LATEST,
+            },
+            _ => AUTO_START_OPTION_IF_LATEST,
+        }
+    }
+
+    /// The 'Default Python Version' setting, or 3.12 when it is unsupported.
+    pub fn get_effective_default_python_version(&self) -> String {
+        match self.get_item_value(DEFAULT_PYTHON_VERSION_CONFIG_KEY) {
+            Some(ConfigValue::String(version))
+                if get_supported_python_versions().contains(&version) =>
+            {
+                version
+            }
+            _ => DEFAULT_PYTHON_VERSION.to_string(),
+        }
+    }
+
+    pub fn get_effective_app_sort_order(&self) -> &'static str {
+        match self.get_item_value(APP_SORT_ORDER_CONFIG_KEY) {
+            Some(ConfigValue::String(value)) => match value.as_str() {
+                APP_SORT_ORDER_OPTION_ALPHABETICAL => APP_SORT_ORDER_OPTION_ALPHABETICAL,
+                APP_SORT_ORDER_OPTION_LAST_START => APP_SORT_ORDER_OPTION_LAST_START,
+                APP_SORT_ORDER_OPTION_INSTALL_DATE => APP_SORT_ORDER_OPTION_INSTALL_DATE,
+                _ => APP_SORT_ORDER_OPTION_RUNNING_FIRST,
+            },
+            _ => APP_SORT_ORDER_OPTION_RUNNING_FIRST,
+        }
+    }
+
+    pub fn get_effective_fetch_on_startup(&self) -> &'static str {
+        match self.get_item_value(FETCH_ON_STARTUP_CONFIG_KEY) {
+            Some(ConfigValue::String(value)) => match value.as_str() {
+                FETCH_ON_STARTUP_OPTION_INTERVAL => FETCH_ON_STARTUP_OPTION_INTERVAL,
+                FETCH_ON_STARTUP_OPTION_NEVER => FETCH_ON_STARTUP_OPTION_NEVER,
+                _ => FETCH_ON_STARTUP_OPTION_ALWAYS,
+            },
+            _ => FETCH_ON_STARTUP_OPTION_ALWAYS,
+        }
+    }
+
+    pub fn get_effective_python_download_mirror(&self) -> &'static str {
+        match self.get_effective_network_region() {
+            NETWORK_REGION_OPTION_CHINA => return PYTHON_DOWNLOAD_MIRROR_OPTION_CHINA,
+            NETWORK_REGION_OPTION_GLOBAL => return PYTHON_DOWNLOAD_MIRROR_OPTION_OFFICIAL,
+            _ => {}
+        }
+        match self.get_item_value(PYTHON_DOWNLOAD_MIRROR_CONFIG_KEY) {
+            Some(ConfigValue::String(value)) => match value.as_str() {
+                PYTHON_DOWNLOAD_MIRROR_OPTION_OFFICIAL => PYTHON_DOWNLOAD_MIRROR_OPTION_OFFICIAL,
+                PYTHON_DOWNLOAD_MIRROR_OPTION_CHINA => PYTHON_DOWNLOAD_MIRROR_OPTION_CHINA,
+                _ => PYTHON_DOWNLOAD_MIRROR_OPTION_AUTO,
+            },
+            _ => PYTHON_DOWNLOAD_MIRROR_OPTION_AUTO,
+        }
+    }
+
+    /// Both region presets auto-detect the proxy; only a custom region can turn it off.
+    pub fn get_effective_git_proxy_auto(&self) -> bool {
+        if self.get_effective_network_region() != NETWORK_REGION_OPTION_CUSTOM {
+            return true;
+        }
+        !matches!(
+            self.get_item_value(GIT_PROXY_CONFIG_KEY),
+            Some(ConfigValue::String(value)) if value == GIT_PROXY_OPTION_OFF
+        )
+    }
+
+    pub fn get_effective_network_retries(&self) -> u32 {
+        match self.get_item_value(NETWORK_RETRIES_CONFIG_KEY) {
+            Some(ConfigValue::Integer(value)) if value >= 0 => value as u32,
+            _ => DEFAULT_NETWORK_RETRIES as u32,
+        }
+    }
+
+    /// Whether a missing Python wipes the whole app instead of being repaired.
+    pub fn get_effective_reset_on_missing_python(&self) -> bool {
+        matches!(
+            self.get_item_value(MISSING_PYTHON_CONFIG_KEY),
+            Some(ConfigValue::String(value)) if value == MISSING_PYTHON_OPTION_RESET
+        )
+    }
+
+    pub fn get_effective_stop_apps_on_exit(&self) -> bool {
+        matches!(
+            self.get_item_value(STOP_APPS_ON_EXIT_CONFIG_KEY),
+            Some(ConfigValue::String(value)) if value == STOP_APPS_ON_EXIT_OPTION_ON
+        )
+    }
+
+    pub fn get_effective_slow_drive_warning(&self) -> bool {
+        !matches!(
+            self.get_item_value(SLOW_DRIVE_WARNING_CONFIG_KEY),
+            Some(ConfigValue::String(value)) if value == SLOW_DRIVE_WARNING_OPTION_OFF
+        )
+    }
+
+    pub fn get_effective_second_launch(&self) -> &'static str {
+        match self.get_item_value(SECOND_LAUNCH_CONFIG_KEY) {
+            Some(ConfigValue::String(value)) => match value.as_str() {
+                SECOND_LAUNCH_OPTION_FORWARD_COMMAND => SECOND_LAUNCH_OPTION_FORWARD_COMMAND,
+                SECOND_LAUNCH_OPTION_SILENT => SECOND_LAUNCH_OPTION_SILENT,
+                _ => SECOND_LAUNCH_OPTION_FOCUS_WINDOW,
+            },
+            _ => SECOND_LAUNCH_OPTION_FOCUS_WINDOW,
+        }
+    }
+
+    pub fn get_effective_status_update_interval_secs(&self) -> u64 {
+        match self.get_item_value(STATUS_UPDATE_INTERVAL_CONFIG_KEY) {
+            Some(ConfigValue::Integer(value)) if value >= 1 => value as u64,
+            _ => DEFAULT_STATUS_UPDATE_INTERVAL_SECS as u64,
+        }
+    }
+
+    pub fn get_effective_max_concurrent_installs(&self) -> usize {
+        match self.get_item_value(MAX_CONCURRENT_INSTALLS_CONFIG_KEY) {
+            Some(ConfigValue::Integer(value)) if value >= 1 => value as usize,
+            _ => DEFAULT_MAX_CONCURRENT_INSTALLS as usize,
+        }
+    }
+
+    pub fn get_effective_config_backups(&self) -> usize {
+        match self.get_item_value(CONFIG_BACKUPS_CONFIG_KEY) {
+            Some(ConfigValue::Integer(value)) if value >= 0 => value as usize,
+            _ => DEFAULT_CONFIG_BACKUPS as usize,
+        }
+    }
+
+    pub fn get_effective_source_address(&self) -> Option<IpAddr> {
+        match self.get_item_value(SOURCE_ADDRESS_CONFIG_KEY) {
+            Some(ConfigValue::String(value)) if !value.trim().is_empty() => {
+                match value.trim().parse() {
+                    Ok(address) => Some(address),
+                    Err(_) => {
+                        warn!("Ignoring invalid Source Address '{}'.", value);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    pub fn get_effective_proxy(&self) -> Option<String> {
+        match self.get_item_value(PROXY_CONFIG_KEY) {
+            Some(ConfigValue::String(value)) if !value.trim().is_empty() => {
+                Some(value.trim().to_string())
+            }
+            _ => None,
+        }
+    }
+
     pub fn get_effective_lang(&self) -> &'static str {
         match self.get_item_value(I18N_CONFIG_KEY) {
             Some(ConfigValue::String(value)) => match value.as_str() {
@@ -546,10 +1213,152 @@ pub type ConfigState = Arc<Mutex<AppConfig>>;
 
 pub static GLOBAL_CONFIG_STATE: OnceCell<ConfigState> = OnceCell::new();
 
+/// Retry budget for network operations; falls back to the default before the config is loaded.
+pub fn get_network_retries() -> u32 {
+    GLOBAL_CONFIG_STATE
+        .get()
+        .map(|state| state.lock().unwrap().get_effective_network_retries())
+        .unwrap_or(DEFAULT_NETWORK_RETRIES as u32)
+}
+
+pub fn get_reset_on_missing_python() -> bool {
+    GLOBAL_CONFIG_STATE.get().is_some_and(|state| {
+        state
+            .lock()
+            .unwrap()
+            .get_effective_reset_on_missing_python()
+    })
+}
+
+pub fn get_network_region() -> &'static str {
+    GLOBAL_CONFIG_STATE
+        .get()
+        .map(|state| state.lock().unwrap().get_effective_region())
+        .unwrap_or_else(|| network_region_from_locale(&get_default_locale()))
+}
+
+pub fn get_git_proxy_auto() -> bool {
+    GLOBAL_CONFIG_STATE
+        .get()
+        .map(|state| state.lock().unwrap().get_effective_git_proxy_auto())
+        .unwrap_or(true)
+}
+
+pub fn get_stop_apps_on_exit() -> bool {
+    GLOBAL_CONFIG_STATE
+        .get()
+        .is_some_and(|state| state.lock().unwrap().get_effective_stop_apps_on_exit())
+}
+
+pub fn get_slow_drive_warning() -> bool {
+    GLOBAL_CONFIG_STATE
+        .get()
+        .map(|state| state.lock().unwrap().get_effective_slow_drive_warning())
+        .unwrap_or(true)
+}
+
+pub fn get_second_launch() -> &'static str {
+    GLOBAL_CONFIG_STATE
+        .get()
+        .map(|state| state.lock().unwrap().get_effective_second_launch())
+        .unwrap_or(SECOND_LAUNCH_OPTION_FOCUS_WINDOW)
+}
+
+pub fn get_app_sort_order() -> &'static str {
+    GLOBAL_CONFIG_STATE
+        .get()
+        .map(|state| state.lock().unwrap().get_effective_app_sort_order())
+        .unwrap_or(APP_SORT_ORDER_OPTION_RUNNING_FIRST)
+}
+
+pub fn get_status_update_interval_secs() -> u64 {
+    GLOBAL_CONFIG_STATE
+        .get()
+        .map(|state| {
+            state
+                .lock()
+                .unwrap()
+                .get_effective_status_update_interval_secs()
+        })
+        .unwrap_or(DEFAULT_STATUS_UPDATE_INTERVAL_SECS as u64)
+}
+
+pub fn get_config_backups() -> usize {
+    GLOBAL_CONFIG_STATE
+        .get()
+        .map(|state| state.lock().unwrap().get_effective_config_backups())
+        .unwrap_or(DEFAULT_CONFIG_BACKUPS as usize)
+}
+
+pub fn get_max_concurrent_installs() -> usize {
+    GLOBAL_CONFIG_STATE
+        .get()
+        .map(|state| {
+            state
+                .lock()
+                .unwrap()
+                .get_effective_max_concurrent_installs()
+        })
+        .unwrap_or(DEFAULT_MAX_CONCURRENT_INSTALLS as usize)
+}
+
+pub fn get_default_python_version() -> String {
+    GLOBAL_CONFIG_STATE
+        .get()
+        .map(|state| state.lock().unwrap().get_effective_default_python_version())
+        .unwrap_or_else(|| DEFAULT_PYTHON_VERSION.to_string())
+}
+
+/// Sent to the UI in place of a stored 'Pip Index Password'; `update_config_item` ignores it
+/// coming back unchanged.
+const MASKED_SECRET: &str = "********";
+
 #[tauri::command]
 pub fn get_config_payload(state: tauri::State<'_, ConfigState>) -> Result<Vec<ConfigItem>, String> {
     let config_manager = state.lock().unwrap();
-    Ok(config_manager.get_all_items_vec())
+    let mut items = config_manager.get_all_items_vec();
+    for item in &mut items {
+        if item.name == PIP_INDEX_PASSWORD_CONFIG_KEY && !item.value.to_string().is_empty() {
+            item.value = ConfigValue::String(MASKED_SECRET.to_string());
+        }
+    }
+    Ok(items)
+}
+
+/// Stderr texts hidden from the UI.
+pub fn get_suppressed_output() -> Vec<String> {
+    match GLOBAL_CONFIG_STATE.get() {
+        Some(state) => state.lock().unwrap().get_effective_suppressed_output(),
+        None => DEFAULT_SUPPRESSED_OUTPUT
+            .split(';')
+            .map(str::to_string)
+            .collect(),
+    }
+}
+
+/// Whether pip runs with -v or -vv, in which case error lines are shown even when they match
+/// 'Suppressed Output', so pip's diagnostics are not filtered out.
+pub fn is_pip_verbose() -> bool {
+    GLOBAL_CONFIG_STATE.get().is_some_and(|state| {
+        matches!(
+            state.lock().unwrap().get_effective_pip_verbosity(),
+            PIP_VERBOSITY_OPTION_VERBOSE | PIP_VERBOSITY_OPTION_VERY_VERBOSE
+        )
+    })
+}
+
+/// Local address to bind outgoing downloads to; `None` before the config is loaded or when unset.
+pub fn get_source_address() -> Option<IpAddr> {
+    GLOBAL_CONFIG_STATE
+        .get()
+        .and_then(|state| state.lock().unwrap().get_effective_source_address())
+}
+
+/// The 'Proxy' setting; `None` before the config is loaded or when unset.
+pub fn get_proxy() -> Option<String> {
+    GLOBAL_CONFIG_STATE
+        .get()
+        .and_then(|state| state.lock().unwrap().get_effective_proxy())
 }
 
 #[tauri::command]
@@ -561,6 +1370,9 @@ pub fn update_config_item(
     let mut config_manager = state.lock().unwrap();
 
     let config_value: ConfigValue = serde_json::from_value(value.clone())?;
+    if name == PIP_INDEX_PASSWORD_CONFIG_KEY && config_value.to_string() == MASKED_SECRET {
+        return Ok(());
+    }
 
     config_manager.update_item_value(&name, config_value);
     Ok(())
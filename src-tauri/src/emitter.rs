@@ -1,8 +1,9 @@
 // src-tauri/src/emit.rs
+use crate::utils::redact::redact_secrets;
 use once_cell::sync::OnceCell;
 use serde::Serialize;
 use tauri::{AppHandle, Emitter, Wry};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 static GLOBAL_APP_HANDLE: OnceCell<AppHandle<Wry>> = OnceCell::new();
 
@@ -22,6 +23,17 @@ struct MessagePayload<'a> {
     finished: bool,
     #[serde(default)]
     error: bool,
+    #[serde(default)]
+    warning: bool,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    durations: &'a [PhaseDuration],
+}
+
+/// Wall-clock time of one phase of a long-running operation, carried on its finish event.
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseDuration {
+    pub phase: String,
+    pub duration_ms: u64,
 }
 
 pub fn get_app_handle() -> Option<&'static AppHandle<Wry>> {
@@ -41,13 +53,43 @@ pub fn emit<S: Serialize + Clone>(event_name: &str, payload: S) {
     }
 }
 
+#[derive(Clone, Serialize)]
+struct CustomEventPayload<'a> {
+    app_name: &'a str,
+    event: &'a str,
+    payload: serde_json::Value,
+}
+
+/// Forwards an event a running app sent through PYAPPIFY_EVENT_SOCKET to the UI as `app-event`.
+pub fn emit_custom_event(app_name: &str, event: &str, payload: serde_json::Value) {
+    debug!("Relaying event '{}' from app '{}'", event, app_name);
+    emit(
+        "app-event",
+        CustomEventPayload {
+            app_name,
+            event,
+            payload,
+        },
+    );
+}
+
+/// Severity of an `app-log` line: warnings are shown apart from normal output without counting
+/// as errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LogLevel {
+    Info,
+    Warn,
+    Error,
+}
+
 #[doc(hidden)]
 pub(crate) fn emit_log_impl(
     app_name: String,
     original_message: &str,
     is_update_param: bool,
-    is_error: bool,
+    level: LogLevel,
 ) {
+    let is_error = level == LogLevel::Error;
     if is_error && original_message.is_empty() {
         error!(
             "Attempted to emit an empty error message for app: {} (update: {})",
@@ -56,14 +98,13 @@ pub(crate) fn emit_log_impl(
         return;
     }
 
-    let mut actual_message = original_message;
+    let redacted_message = redact_secrets(original_message);
+    let mut actual_message = redacted_message.as_ref();
     let mut final_is_update = is_update_param;
 
-    if original_message.ends_with('\r') {
+    if let Some(stripped) = actual_message.strip_suffix('\r') {
         final_is_update = true;
-        actual_message = original_message
-            .strip_suffix('\r')
-            .unwrap_or(original_message);
+        actual_message = stripped;
     }
 
     emit(
@@ -74,21 +115,50 @@ pub(crate) fn emit_log_impl(
             update: final_is_update,
             finished: false,
             error: is_error,
+            warning: level == LogLevel::Warn,
+            durations: &[],
         },
     );
 
     let prefix = if final_is_update { "UPDATE " } else { "" };
-    let log_type = if is_error { "ERROR" } else { "INFO" };
 
-    if is_error {
-        error!("{}{} [{}]: {}", prefix, log_type, app_name, actual_message);
-    } else if !final_is_update {
-        info!("{}{} [{}]: {}", prefix, log_type, app_name, actual_message);
+    match level {
+        LogLevel::Error => error!("{}ERROR [{}]: {}", prefix, app_name, actual_message),
+        LogLevel::Warn => warn!("{}WARN [{}]: {}", prefix, app_name, actual_message),
+        LogLevel::Info if !final_is_update => {
+            info!("{}INFO [{}]: {}", prefix, app_name, actual_message)
+        }
+        LogLevel::Info => {}
     }
 }
 
+#[cfg(test)]
+static RECORDED_FINISHES: std::sync::Mutex<Vec<(String, bool)>> = std::sync::Mutex::new(Vec::new());
+
+#[cfg(test)]
+pub(crate) fn take_recorded_finishes(app_name: &str) -> Vec<bool> {
+    let mut recorded = RECORDED_FINISHES.lock().unwrap();
+    let (matching, rest): (Vec<_>, Vec<_>) =
+        recorded.drain(..).partition(|(name, _)| name == app_name);
+    *recorded = rest;
+    matching.into_iter().map(|(_, is_error)| is_error).collect()
+}
+
 #[doc(hidden)]
 pub(crate) fn emit_finish_impl(app_name: String, is_error: bool) {
+    emit_finish_with_durations(app_name, is_error, &[]);
+}
+
+pub(crate) fn emit_finish_with_durations(
+    app_name: String,
+    is_error: bool,
+    durations: &[PhaseDuration],
+) {
+    #[cfg(test)]
+    RECORDED_FINISHES
+        .lock()
+        .unwrap()
+        .push((app_name.clone(), is_error));
     emit(
         "app-log",
         MessagePayload {
@@ -97,6 +167,8 @@ pub(crate) fn emit_finish_impl(app_name: String, is_error: bool) {
             update: false,
             finished: true,
             error: is_error,
+            warning: false,
+            durations,
         },
     );
     let status = if is_error { "FAILED" } else { "COMPLETED" };
@@ -106,40 +178,50 @@ pub(crate) fn emit_finish_impl(app_name: String, is_error: bool) {
 #[macro_export]
 macro_rules! emit_info {
     ($app_name:expr, $fmt:literal $(, $($args:tt)*)?) => {
-        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!($fmt $(, $($args)*)?), false, false);
+        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!($fmt $(, $($args)*)?), false, $crate::emitter::LogLevel::Info);
     };
     ($app_name:expr, $message:expr) => {
-        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!("{}", $message), false, false);
+        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!("{}", $message), false, $crate::emitter::LogLevel::Info);
     };
 }
 
 #[macro_export]
 macro_rules! emit_error {
     ($app_name:expr, $fmt:literal $(, $($args:tt)*)?) => {
-        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!($fmt $(, $($args)*)?), false, true);
+        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!($fmt $(, $($args)*)?), false, $crate::emitter::LogLevel::Error);
+    };
+    ($app_name:expr, $message:expr) => {
+        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!("{}", $message), false, $crate::emitter::LogLevel::Error);
+    };
+}
+
+#[macro_export]
+macro_rules! emit_warn {
+    ($app_name:expr, $fmt:literal $(, $($args:tt)*)?) => {
+        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!($fmt $(, $($args)*)?), false, $crate::emitter::LogLevel::Warn);
     };
     ($app_name:expr, $message:expr) => {
-        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!("{}", $message), false, true);
+        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!("{}", $message), false, $crate::emitter::LogLevel::Warn);
     };
 }
 
 #[macro_export]
 macro_rules! emit_update_info {
     ($app_name:expr, $fmt:literal $(, $($args:tt)*)?) => {
-        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!($fmt $(, $($args)*)?), true, false);
+        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!($fmt $(, $($args)*)?), true, $crate::emitter::LogLevel::Info);
     };
     ($app_name:expr, $message:expr) => {
-        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!("{}", $message), true, false);
+        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!("{}", $message), true, $crate::emitter::LogLevel::Info);
     };
 }
 
 #[macro_export]
 macro_rules! emit_update_error {
     ($app_name:expr, $fmt:literal $(, $($args:tt)*)?) => {
-        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!($fmt $(, $($args)*)?), true, true);
+        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!($fmt $(, $($args)*)?), true, $crate::emitter::LogLevel::Error);
     };
     ($app_name:expr, $message:expr) => {
-        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!("{}", $message), true, true);
+        $crate::emitter::emit_log_impl($app_name.to_string(), &::std::format!("{}", $message), true, $crate::emitter::LogLevel::Error);
     };
 }
 
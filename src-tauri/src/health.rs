@@ -0,0 +1,87 @@
+// src/health.rs
+use crate::app::{HealthCheck, HealthStatus, Profile};
+use crate::utils::command::{new_cmd, split_command_line};
+use crate::utils::path::get_python_dir;
+use crate::utils::process::RemovePythonEnvsExt;
+use reqwest::Client;
+use std::path::Path;
+use std::process::Stdio;
+use tokio::time::{timeout, Duration};
+use tracing::{debug, warn};
+
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Evaluates the profile's `health_check` once. Returns `Unknown` when the profile has none.
+pub async fn evaluate(app_name: &str, profile: &Profile, working_dir: &Path) -> HealthStatus {
+    let Some(check) = &profile.health_check else {
+        return HealthStatus::Unknown;
+    };
+    let launch_dir = profile.resolve_cwd(working_dir);
+    let healthy = if !check.command.is_empty() {
+        check_command(app_name, check, &launch_dir).await
+    } else if !check.url.is_empty() {
+        check_url(&check.url).await
+    } else if !check.file.is_empty() {
+        launch_dir.join(&check.file).exists()
+    } else {
+        warn!(
+            "health_check of profile '{}' has no command, url or file.",
+            profile.name
+        );
+        return HealthStatus::Unknown;
+    };
+    if healthy {
+        HealthStatus::Healthy
+    } else {
+        HealthStatus::Unhealthy
+    }
+}
+
+async fn check_command(app_name: &str, check: &HealthCheck, launch_dir: &Path) -> bool {
+    let mut parts = match split_command_line(&check.command) {
+        Ok(parts) => parts.into_iter(),
+        Err(e) => {
+            warn!("Invalid health check command: {}", e);
+            return false;
+        }
+    };
+    let Some(executable) = parts.next() else {
+        return false;
+    };
+    let mut cmd = new_cmd(executable);
+    cmd.args(parts)
+        .current_dir(launch_dir)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .kill_on_drop(true);
+    cmd.clear_python_envs()
+        .prepend_python_path(&get_python_dir(app_name));
+    match timeout(HEALTH_CHECK_TIMEOUT, cmd.status()).await {
+        Ok(Ok(status)) => status.success(),
+        Ok(Err(e)) => {
+            debug!("Health check command '{}' failed: {}", check.command, e);
+            false
+        }
+        Err(_) => {
+            debug!("Health check command '{}' timed out.", check.command);
+            false
+        }
+    }
+}
+
+async fn check_url(url: &str) -> bool {
+    let client = match Client::builder().timeout(HEALTH_CHECK_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("Failed to build HTTP client for health check: {}", e);
+            return false;
+        }
+    };
+    match client.get(url).send().await {
+        Ok(response) => response.status().is_success(),
+        Err(e) => {
+            debug!("Health check GET {} failed: {}", url, e);
+            false
+        }
+    }
+}
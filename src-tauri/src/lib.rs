@@ -5,66 +5,149 @@ mod config_manager;
 mod emitter;
 mod execute_python;
 mod git;
+mod health;
+mod lockfile;
+mod manifest;
 mod python_env;
 mod runas;
 mod submodule;
 mod utils;
 
 use crate::app_service::{
-    delete_app, get_update_notes, load_apps, setup_app, start_app, stop_app, update_to_version,
-    AUTO_START_CHECKED,
+    add_app, cancel_setup, check_app_health, compact_app, compute_working_dir_manifest, delete_app,
+    get_app_disk_usage, get_apps_snapshot, get_effective_pip_settings, get_effective_profile,
+    get_last_run, get_total_disk_usage, get_update_notes, get_update_notes_to_latest, is_safe_mode,
+    load_apps, open_app_folder, open_app_homepage, pause_status_updates, refresh_app, remove_app,
+    repair_app, resume_status_updates, rollback_pending_update, set_active_profile, setup_app,
+    start_all, start_app, stop_all, stop_app, test_network, uninstall_app, update_all_to_latest,
+    update_to_version, verify_working_dir, AUTO_START_CHECKED,
 };
 use crate::config_manager::{
-    get_config_payload, init_config_manager, save_configuration, update_config_item,
+    get_config_payload, get_second_launch, init_config_manager, save_configuration,
+    update_config_item, SECOND_LAUNCH_OPTION_FOCUS_WINDOW, SECOND_LAUNCH_OPTION_FORWARD_COMMAND,
 };
 use crate::utils::defender::add_defender_exclusion;
 use crate::utils::logger::LoggerBuilder;
 use crate::utils::window;
 use crate::utils::window::{on_window_event, send_notification_cmd};
 use std::env;
-use tauri::Manager;
-use tracing::info;
+use tauri::{AppHandle, Manager};
+use tracing::{error, info};
 #[macro_use]
 extern crate rust_i18n;
 i18n!("locales", fallback = "en");
 
-fn has_cli_command() -> bool {
-    let args: Vec<String> = env::args().collect();
-    let mut has_command_flag = false;
-    let mut i = 1;
-    while i < args.len() {
-        if args[i].as_str() == "-c" {
-            has_command_flag = true;
-            break;
-        }
-        i += 1;
-    }
-    has_command_flag || env::var("PYAPPIFY_COMMAND").is_ok()
-}
+/// Handled by the UI instance rather than CLI mode, so the startup shortcut
+/// (`-c start -n <app>`) reaches an already running pyappify.
+const START_COMMAND: &str = "start";
 
-async fn handle_command_line() {
-    {
-        let mut auto_start_lock = AUTO_START_CHECKED.lock().await;
-        *auto_start_lock = true;
-    }
-    let args: Vec<String> = env::args().collect();
-    let mut command = None;
-    let mut profile_name = None;
+#[derive(Debug, Default, PartialEq)]
+struct CliArgs {
+    command: Option<String>,
+    profile_name: Option<String>,
+    app_name: Option<String>,
+    safe_mode: bool,
+    /// Everything pyappify does not consume itself, forwarded to the Python app.
+    app_args: Vec<String>,
+}
 
+fn parse_cli_args(args: &[String]) -> CliArgs {
+    let mut cli_args = CliArgs::default();
     let mut i = 1;
     while i < args.len() {
         match args[i].as_str() {
             "-c" => {
-                command = args.get(i + 1).cloned();
+                cli_args.command = args.get(i + 1).cloned();
                 i += 2;
             }
             "-p" => {
-                profile_name = args.get(i + 1).cloned();
+                cli_args.profile_name = args.get(i + 1).cloned();
                 i += 2;
             }
-            _ => i += 1,
+            "-n" => {
+                cli_args.app_name = args.get(i + 1).cloned();
+                i += 2;
+            }
+            // Read by `app::read_embedded_app`.
+            "--yml" => i += 2,
+            "--safe-mode" => {
+                cli_args.safe_mode = true;
+                i += 1;
+            }
+            arg => {
+                cli_args.app_args.push(arg.to_string());
+                i += 1;
+            }
+        }
+    }
+    cli_args
+}
+
+fn has_cli_command() -> bool {
+    let args: Vec<String> = env::args().collect();
+    parse_cli_args(&args)
+        .command
+        .or_else(|| env::var("PYAPPIFY_COMMAND").ok())
+        .is_some_and(|command| command != START_COMMAND)
+}
+
+/// The app a `-c start` launch asks for: the one named with `-n`, or the embedded app.
+fn start_command_app(cli_args: &CliArgs) -> Option<String> {
+    if cli_args.command.as_deref() != Some(START_COMMAND) {
+        return None;
+    }
+    cli_args
+        .app_name
+        .clone()
+        .or_else(|| app::read_embedded_app().ok().map(|app| app.name))
+}
+
+fn start_app_from_command(app_handle: AppHandle, app_name: String) {
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = load_apps().await {
+            error!("Failed to load apps to start '{}': {}", app_name, e);
+            return;
         }
+        if let Err(e) = start_app(app_handle, app_name.clone(), None).await {
+            error!(
+                "Failed to start '{}' from the command line: {}",
+                app_name, e
+            );
+        }
+    });
+}
+
+/// A second launch while pyappify runs, handled as the 'Second Launch' setting says.
+fn on_second_instance(app_handle: &AppHandle, args: Vec<String>) {
+    let requested_app = start_command_app(&parse_cli_args(&args));
+    match (get_second_launch(), requested_app) {
+        (SECOND_LAUNCH_OPTION_FOCUS_WINDOW, _) | (SECOND_LAUNCH_OPTION_FORWARD_COMMAND, None) => {
+            window::show_and_focus_main_window(app_handle);
+        }
+        (_, Some(app_name)) => {
+            info!("Starting '{}' for a second launch", app_name);
+            start_app_from_command(app_handle.clone(), app_name);
+        }
+        (_, None) => info!("Ignoring second launch without a command"),
     }
+}
+
+fn has_safe_mode_flag() -> bool {
+    let args: Vec<String> = env::args().collect();
+    parse_cli_args(&args).safe_mode || env::var("PYAPPIFY_SAFE_MODE").is_ok()
+}
+
+async fn handle_command_line() {
+    {
+        let mut auto_start_lock = AUTO_START_CHECKED.lock().await;
+        *auto_start_lock = true;
+    }
+    let args: Vec<String> = env::args().collect();
+    let CliArgs {
+        mut command,
+        mut profile_name,
+        ..
+    } = parse_cli_args(&args);
 
     if command.is_none() {
         command = env::var("PYAPPIFY_COMMAND").ok();
@@ -83,15 +166,33 @@ async fn handle_command_line() {
                 }
             };
 
-            if let Some(app) = apps.first() {
+            let embedded_app_name = app::read_embedded_app()
+                .map(|embedded_app| embedded_app.name)
+                .unwrap_or_default();
+            if let Some(app) = apps
+                .iter()
+                .find(|app| app.name == embedded_app_name)
+                .or(apps.first())
+            {
                 let a_name = &app.name;
                 println!(
                     "Command-line mode: Setting up app '{}' with profile '{}'.",
                     a_name, p_name
                 );
                 match setup_app(a_name, &p_name).await {
-                    Ok(_path) => {
-                        println!("Setup successful.");
+                    Ok(result) => {
+                        println!(
+                            "Setup successful: version {}, Python {}, {} packages{}, took {:.1}s.",
+                            result.version.as_deref().unwrap_or("unknown"),
+                            result.python_version.as_deref().unwrap_or("none"),
+                            result.packages_installed,
+                            if result.skipped_pip {
+                                " (pip skipped)"
+                            } else {
+                                ""
+                            },
+                            result.duration_ms as f64 / 1000.0
+                        );
                         std::process::exit(0);
                     }
                     Err(e) => {
@@ -235,6 +336,10 @@ pub async fn run() {
         std::env::set_var("WEBVIEW2_USER_DATA_FOLDER", cwd);
     }
 
+    if has_safe_mode_flag() {
+        app_service::enable_safe_mode();
+    }
+
     if has_cli_command() {
         info!("running in cli");
         let context = tauri::generate_context!();
@@ -248,7 +353,7 @@ pub async fn run() {
         tauri::Builder::default()
             .plugin(tauri_plugin_single_instance::init(|app, args, cwd| {
                 info!("tauri_plugin_single_instance args:{:?} cwd:{}", args, cwd);
-                window::show_and_focus_main_window(app.app_handle());
+                on_second_instance(app.app_handle(), args);
             }))
             .on_window_event(on_window_event)
             .plugin(tauri_plugin_opener::init())
@@ -258,6 +363,7 @@ pub async fn run() {
                 let app_handle = app.handle();
                 emitter::init_app_handle(app_handle.clone());
                 init_config_manager(&app_handle);
+                python_env::clean_stale_temp_archives();
                 tokio::spawn(app_service::periodically_update_all_apps_running_status(
                     app_handle.clone(),
                 ));
@@ -268,10 +374,38 @@ pub async fn run() {
                 start_app,
                 stop_app,
                 load_apps,
+                get_apps_snapshot,
                 setup_app,
+                repair_app,
+                rollback_pending_update,
                 delete_app,
+                uninstall_app,
                 get_update_notes,
+                get_update_notes_to_latest,
                 update_to_version,
+                cancel_setup,
+                get_last_run,
+                add_app,
+                remove_app,
+                start_all,
+                stop_all,
+                update_all_to_latest,
+                check_app_health,
+                compact_app,
+                get_app_disk_usage,
+                get_total_disk_usage,
+                compute_working_dir_manifest,
+                verify_working_dir,
+                is_safe_mode,
+                pause_status_updates,
+                resume_status_updates,
+                get_effective_pip_settings,
+                get_effective_profile,
+                test_network,
+                open_app_folder,
+                open_app_homepage,
+                refresh_app,
+                set_active_profile,
                 update_config_item,
                 save_configuration,
                 get_config_payload,
@@ -282,3 +416,45 @@ pub async fn run() {
             .expect("error while running tauri application");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_cli_args, start_command_app, CliArgs};
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parses_the_startup_shortcut_command() {
+        let cli_args = parse_cli_args(&args("pyappify.exe --yml other.yml -c start -n my-app"));
+        assert_eq!(
+            cli_args,
+            CliArgs {
+                command: Some("start".to_string()),
+                profile_name: None,
+                app_name: Some("my-app".to_string()),
+                safe_mode: false,
+                app_args: Vec::new(),
+            }
+        );
+        assert_eq!(start_command_app(&cli_args), Some("my-app".to_string()));
+
+        let setup = parse_cli_args(&args("pyappify.exe -c setup -p release"));
+        assert_eq!(setup.profile_name.as_deref(), Some("release"));
+        assert_eq!(start_command_app(&setup), None);
+        assert_eq!(
+            start_command_app(&parse_cli_args(&args("pyappify.exe"))),
+            None
+        );
+    }
+
+    #[test]
+    fn keeps_launcher_flags_out_of_app_args() {
+        let cli_args = parse_cli_args(&args(
+            "pyappify.exe --safe-mode --port 8080 --yml other.yml -c start -n my-app -v",
+        ));
+        assert!(cli_args.safe_mode);
+        assert_eq!(cli_args.app_args, vec!["--port", "8080", "-v"]);
+    }
+}
@@ -0,0 +1,261 @@
+// src/lockfile.rs
+use crate::emit_info;
+use crate::utils::path::get_app_lock_requirements_path;
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use tracing::warn;
+
+pub const POETRY_LOCK: &str = "poetry.lock";
+pub const PIPFILE_LOCK: &str = "Pipfile.lock";
+
+pub fn is_lockfile_spec(spec: &str) -> bool {
+    spec.ends_with(POETRY_LOCK) || spec.ends_with(PIPFILE_LOCK)
+}
+
+/// Converts the lockfile named by `spec` into a pip requirements file under the app's base dir,
+/// so the working dir stays untouched, and returns its absolute path.
+pub fn export_requirements(app_name: &str, project_dir: &Path, spec: &str) -> Result<String> {
+    let lock_path = project_dir.join(spec);
+    let content = fs::read_to_string(&lock_path)
+        .with_context(|| format!("Failed to read lockfile {}", lock_path.display()))?;
+    let requirements = if spec.ends_with(POETRY_LOCK) {
+        parse_poetry_lock(&content)
+    } else {
+        parse_pipfile_lock(&content)
+    }
+    .with_context(|| format!("Failed to parse {}", lock_path.display()))?;
+    if requirements.is_empty() {
+        return Err(anyhow!(
+            "No installable packages found in {}",
+            lock_path.display()
+        ));
+    }
+
+    let exported_path = get_app_lock_requirements_path(app_name);
+    fs::write(&exported_path, requirements.join("\n") + "\n")
+        .with_context(|| format!("Failed to write {}", exported_path.display()))?;
+    emit_info!(
+        app_name,
+        "Exported {} pinned packages from {} to {}",
+        requirements.len(),
+        spec,
+        exported_path.display()
+    );
+    Ok(exported_path.to_string_lossy().into_owned())
+}
+
+#[derive(Deserialize)]
+struct PoetryLock {
+    #[serde(default)]
+    package: Vec<PoetryPackage>,
+}
+
+#[derive(Deserialize)]
+struct PoetryPackage {
+    name: String,
+    version: String,
+    #[serde(default)]
+    optional: bool,
+    /// Poetry < 1.2 marks dev dependencies with `category = "dev"`.
+    category: Option<String>,
+    /// Poetry >= 1.2 lists the dependency groups instead.
+    groups: Option<Vec<String>>,
+    /// Either a marker string or, in newer lockfiles, a table of markers per group.
+    markers: Option<toml::Value>,
+    source: Option<PoetrySource>,
+}
+
+#[derive(Deserialize)]
+struct PoetrySource {
+    #[serde(rename = "type")]
+    source_type: String,
+    #[serde(default)]
+    url: String,
+    reference: Option<String>,
+    resolved_reference: Option<String>,
+}
+
+impl PoetryPackage {
+    fn is_main(&self) -> bool {
+        match (&self.groups, &self.category) {
+            (Some(groups), _) => groups.iter().any(|group| group == "main"),
+            (None, Some(category)) => category != "dev",
+            (None, None) => true,
+        }
+    }
+
+    fn markers(&self) -> Option<&str> {
+        let markers = match self.markers.as_ref()? {
+            toml::Value::String(markers) => Some(markers.as_str()),
+            toml::Value::Table(markers) => markers.get("main").and_then(toml::Value::as_str),
+            _ => None,
+        };
+        markers.filter(|markers| !markers.is_empty())
+    }
+
+    fn to_requirement(&self) -> Option<String> {
+        if self.optional || !self.is_main() {
+            return None;
+        }
+        let requirement = match &self.source {
+            None => format!("{}=={}", self.name, self.version),
+            Some(source) => match source.source_type.as_str() {
+                "legacy" => format!("{}=={}", self.name, self.version),
+                "git" => format!(
+                    "{} @ git+{}@{}",
+                    self.name,
+                    source.url,
+                    source
+                        .resolved_reference
+                        .as_deref()
+                        .or(source.reference.as_deref())
+                        .unwrap_or("HEAD")
+                ),
+                "url" => format!("{} @ {}", self.name, source.url),
+                other => {
+                    warn!(
+                        "Skipping poetry package '{}' with unsupported source type '{}'",
+                        self.name, other
+                    );
+                    return None;
+                }
+            },
+        };
+        match self.markers() {
+            Some(markers) => Some(format!("{} ; {}", requirement, markers)),
+            None => Some(requirement),
+        }
+    }
+}
+
+/// Reads the `[[package]]` tables of a poetry.lock, keeping packages in the main group.
+fn parse_poetry_lock(content: &str) -> Result<Vec<String>> {
+    let lock: PoetryLock = toml::from_str(content)?;
+    Ok(lock
+        .package
+        .iter()
+        .filter_map(PoetryPackage::to_requirement)
+        .collect())
+}
+
+/// Reads the `default` section of a Pipfile.lock; `develop` packages are not installed.
+fn parse_pipfile_lock(content: &str) -> Result<Vec<String>> {
+    let lock: Value = serde_json::from_str(content)?;
+    let default = lock
+        .get("default")
+        .and_then(Value::as_object)
+        .ok_or_else(|| anyhow!("Pipfile.lock has no 'default' section"))?;
+
+    let mut requirements = Vec::new();
+    for (name, details) in default {
+        let extras = details
+            .get("extras")
+            .and_then(Value::as_array)
+            .map(|extras| {
+                extras
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .filter(|extras| !extras.is_empty())
+            .map(|extras| format!("[{}]", extras))
+            .unwrap_or_default();
+        let requirement = if let Some(version) = details.get("version").and_then(Value::as_str) {
+            format!("{}{}{}", name, extras, version)
+        } else if let Some(git) = details.get("git").and_then(Value::as_str) {
+            let reference = details.get("ref").and_then(Value::as_str).unwrap_or("HEAD");
+            format!("{}{} @ git+{}@{}", name, extras, git, reference)
+        } else {
+            warn!("Skipping Pipfile.lock package '{}' without a version", name);
+            continue;
+        };
+        match details.get("markers").and_then(Value::as_str) {
+            Some(markers) => requirements.push(format!("{} ; {}", requirement, markers)),
+            None => requirements.push(requirement),
+        }
+    }
+    Ok(requirements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_pipfile_lock, parse_poetry_lock};
+
+    #[test]
+    fn exports_main_poetry_packages() {
+        let lock = r#"
+[[package]]
+name = "requests"
+version = "2.32.3"
+optional = false
+groups = ["main"]
+
+[package.dependencies]
+idna = ">=2.5,<4"
+
+[[package]]
+name = "pytest"
+version = "8.3.2"
+groups = ["dev"]
+
+[[package]]
+name = "colorama"
+version = "0.4.6"
+category = "main"
+markers = "sys_platform == \"win32\""
+
+[[package]]
+name = "tomli"
+version = "2.0.1"
+groups = ["main", "dev"]
+markers = {main = "python_version < \"3.11\"", dev = "python_version < \"3.12\""}
+
+[[package]]
+name = "mylib"
+version = "0.1.0"
+
+[package.source]
+type = "git"
+url = "https://example.com/mylib.git"
+reference = "main"
+resolved_reference = "abc123"
+"#;
+
+        assert_eq!(
+            parse_poetry_lock(lock).unwrap(),
+            vec![
+                "requests==2.32.3",
+                "colorama==0.4.6 ; sys_platform == \"win32\"",
+                "tomli==2.0.1 ; python_version < \"3.11\"",
+                "mylib @ git+https://example.com/mylib.git@abc123",
+            ]
+        );
+    }
+
+    #[test]
+    fn exports_default_pipfile_packages() {
+        let lock = r#"{
+            "_meta": {},
+            "default": {
+                "requests": {"version": "==2.32.3", "extras": ["socks"]},
+                "pywin32": {"version": "==306", "markers": "sys_platform == 'win32'"},
+                "localpkg": {"path": "."}
+            },
+            "develop": {"pytest": {"version": "==8.3.2"}}
+        }"#;
+
+        let mut requirements = parse_pipfile_lock(lock).unwrap();
+        requirements.sort();
+        assert_eq!(
+            requirements,
+            vec![
+                "pywin32==306 ; sys_platform == 'win32'",
+                "requests[socks]==2.32.3",
+            ]
+        );
+    }
+}
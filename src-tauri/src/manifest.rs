@@ -0,0 +1,168 @@
+// src/manifest.rs
+use crate::python_env::PIP_UPDATE_NEEDED_MARKER;
+use anyhow::{Context, Result};
+use git2::{ObjectType, Oid};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Size and git blob hash of a working dir file, keyed by its '/'-separated relative path.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub size: u64,
+    pub hash: String,
+}
+
+pub type Manifest = BTreeMap<String, ManifestEntry>;
+
+/// Files whose content changed, that disappeared, or that appeared since the manifest was taken.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ManifestDrift {
+    pub modified: Vec<String>,
+    pub missing: Vec<String>,
+    pub extra: Vec<String>,
+}
+
+impl ManifestDrift {
+    pub fn is_clean(&self) -> bool {
+        self.modified.is_empty() && self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+/// Bytecode caches, egg-info from editable installs and pyappify's own temp files change
+/// without anyone tampering with the app.
+fn is_generated(relative_path: &str) -> bool {
+    relative_path.split('/').any(|component| {
+        component == "__pycache__"
+            || component.ends_with(".egg-info")
+            || component == PIP_UPDATE_NEEDED_MARKER
+    })
+}
+
+/// Manifest of `working_dir`, leaving out `preserved` paths (the profile's `preserve_paths`),
+/// which hold user data that is meant to change.
+pub fn compute_manifest(working_dir: &Path, preserved: &[PathBuf]) -> Result<Manifest> {
+    let mut manifest = Manifest::new();
+    for entry in WalkDir::new(working_dir) {
+        let entry = entry.with_context(|| format!("Failed to walk {}", working_dir.display()))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(working_dir)
+            .with_context(|| format!("{} is outside the working dir", entry.path().display()))?;
+        if preserved.iter().any(|path| relative.starts_with(path)) {
+            continue;
+        }
+        let relative_path = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if is_generated(&relative_path) {
+            continue;
+        }
+        let metadata = entry
+            .metadata()
+            .with_context(|| format!("Failed to stat {}", entry.path().display()))?;
+        let hash = Oid::hash_file(ObjectType::Blob, entry.path())
+            .with_context(|| format!("Failed to hash {}", entry.path().display()))?;
+        manifest.insert(
+            relative_path,
+            ManifestEntry {
+                size: metadata.len(),
+                hash: hash.to_string(),
+            },
+        );
+    }
+    Ok(manifest)
+}
+
+pub fn diff_manifest(expected: &Manifest, actual: &Manifest) -> ManifestDrift {
+    let mut drift = ManifestDrift::default();
+    for (path, expected_entry) in expected {
+        match actual.get(path) {
+            Some(actual_entry) if actual_entry != expected_entry => {
+                drift.modified.push(path.clone())
+            }
+            Some(_) => {}
+            None => drift.missing.push(path.clone()),
+        }
+    }
+    drift.extra = actual
+        .keys()
+        .filter(|path| !expected.contains_key(*path))
+        .cloned()
+        .collect();
+    drift
+}
+
+pub fn save_manifest(manifest_path: &Path, manifest: &Manifest) -> Result<()> {
+    let contents =
+        serde_json::to_string_pretty(manifest).context("Failed to serialize manifest")?;
+    fs::write(manifest_path, contents)
+        .with_context(|| format!("Failed to write {}", manifest_path.display()))
+}
+
+pub fn load_manifest(manifest_path: &Path) -> Result<Manifest> {
+    let contents = fs::read_to_string(manifest_path)
+        .with_context(|| format!("Failed to read {}", manifest_path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse {}", manifest_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compute_manifest, diff_manifest};
+    use std::fs;
+    use std::path::PathBuf;
+
+    #[test]
+    fn detects_modified_missing_and_extra_files() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        fs::create_dir_all(dir.join("pkg").join("__pycache__")).unwrap();
+        fs::write(dir.join("main.py"), "print('hi')\n").unwrap();
+        fs::write(dir.join("pkg").join("util.py"), "X = 1\n").unwrap();
+        fs::write(
+            dir.join("pkg").join("__pycache__").join("util.pyc"),
+            "cache",
+        )
+        .unwrap();
+        let expected = compute_manifest(&dir, &[]).unwrap();
+        assert_eq!(
+            expected.keys().collect::<Vec<_>>(),
+            vec!["main.py", "pkg/util.py"]
+        );
+
+        fs::write(dir.join("main.py"), "print('tampered')\n").unwrap();
+        fs::remove_file(dir.join("pkg").join("util.py")).unwrap();
+        fs::write(dir.join("extra.dll"), "new").unwrap();
+        let drift = diff_manifest(&expected, &compute_manifest(&dir, &[]).unwrap());
+        assert_eq!(drift.modified, vec!["main.py"]);
+        assert_eq!(drift.missing, vec!["pkg/util.py"]);
+        assert_eq!(drift.extra, vec!["extra.dll"]);
+        assert!(!drift.is_clean());
+    }
+
+    #[test]
+    fn leaves_preserved_paths_out_of_the_manifest() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        fs::create_dir_all(dir.join("data").join("saves")).unwrap();
+        fs::write(dir.join("main.py"), "print('hi')\n").unwrap();
+        fs::write(dir.join("config.json"), "{}").unwrap();
+        fs::write(dir.join("data").join("saves").join("1.sav"), "save").unwrap();
+        let preserved = vec![PathBuf::from("data"), PathBuf::from("config.json")];
+        let expected = compute_manifest(&dir, &preserved).unwrap();
+        assert_eq!(expected.keys().collect::<Vec<_>>(), vec!["main.py"]);
+
+        fs::write(dir.join("config.json"), "{\"theme\": \"dark\"}").unwrap();
+        fs::write(dir.join("data").join("saves").join("2.sav"), "save").unwrap();
+        let drift = diff_manifest(&expected, &compute_manifest(&dir, &preserved).unwrap());
+        assert!(drift.is_clean());
+    }
+}
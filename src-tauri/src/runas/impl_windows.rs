@@ -10,6 +10,7 @@ use windows_sys::Win32::System::Com::{
     CoInitializeEx, COINIT_APARTMENTTHREADED, COINIT_DISABLE_OLE1DDE,
 };
 use windows_sys::Win32::System::Threading::GetExitCodeProcess;
+use windows_sys::Win32::System::Threading::GetProcessId;
 use windows_sys::Win32::System::Threading::WaitForSingleObject;
 use windows_sys::Win32::System::Threading::INFINITE;
 use windows_sys::Win32::UI::Shell::SEE_MASK_NOASYNC;
@@ -19,7 +20,12 @@ use windows_sys::Win32::UI::WindowsAndMessaging::{SW_HIDE, SW_NORMAL};
 
 use crate::runas::Command;
 
-unsafe fn win_runas(cmd: *const c_ushort, args: *const c_ushort, show: bool) -> u32 {
+unsafe fn win_runas<F: FnOnce(u32)>(
+    cmd: *const c_ushort,
+    args: *const c_ushort,
+    show: bool,
+    on_started: F,
+) -> u32 {
     let mut code = 0;
     let mut sei: SHELLEXECUTEINFOW = mem::zeroed();
     let verb = "runas\0".encode_utf16().collect::<Vec<u16>>();
@@ -38,6 +44,7 @@ unsafe fn win_runas(cmd: *const c_ushort, args: *const c_ushort, show: bool) ->
     if ShellExecuteExW(&mut sei) == 0 || sei.hProcess == ptr::null_mut() as _ {
         return !0;
     }
+    on_started(GetProcessId(sei.hProcess));
 
     WaitForSingleObject(sei.hProcess, INFINITE);
 
@@ -49,6 +56,13 @@ unsafe fn win_runas(cmd: *const c_ushort, args: *const c_ushort, show: bool) ->
 }
 
 pub fn runas_impl(cmd: &Command) -> io::Result<ExitStatus> {
+    runas_impl_with_started(cmd, |_| {})
+}
+
+pub fn runas_impl_with_started<F: FnOnce(u32)>(
+    cmd: &Command,
+    on_started: F,
+) -> io::Result<ExitStatus> {
     let mut params = String::new();
     for arg in cmd.args.iter() {
         let arg = arg.to_string_lossy();
@@ -84,6 +98,7 @@ pub fn runas_impl(cmd: &Command) -> io::Result<ExitStatus> {
             file.as_ptr(),
             params.as_ptr(),
             !cmd.hide,
+            on_started,
         )))
     }
 }
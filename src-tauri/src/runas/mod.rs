@@ -124,4 +124,12 @@ impl Command {
         use impl_windows::runas_impl;
         runas_impl(self)
     }
+
+    /// Like `status`, but calls `on_started` with the PID of the elevated process once it is
+    /// running, before waiting for it to finish. It is not called when elevation is refused or
+    /// the launch fails.
+    #[cfg(windows)]
+    pub fn status_with_started<F: FnOnce(u32)>(&mut self, on_started: F) -> io::Result<ExitStatus> {
+        impl_windows::runas_impl_with_started(self, on_started)
+    }
 }
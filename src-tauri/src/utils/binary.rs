@@ -0,0 +1,152 @@
+// src/utils/binary.rs
+use anyhow::{Context, Result};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// OS and CPU architecture a native executable was built for, named like `std::env::consts`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutableTarget {
+    pub os: &'static str,
+    pub arch: &'static str,
+}
+
+fn read_u16(bytes: &[u8], offset: usize, big_endian: bool) -> Option<u16> {
+    let raw: [u8; 2] = bytes.get(offset..offset + 2)?.try_into().ok()?;
+    Some(if big_endian {
+        u16::from_be_bytes(raw)
+    } else {
+        u16::from_le_bytes(raw)
+    })
+}
+
+fn read_u32(bytes: &[u8], offset: usize, big_endian: bool) -> Option<u32> {
+    let raw: [u8; 4] = bytes.get(offset..offset + 4)?.try_into().ok()?;
+    Some(if big_endian {
+        u32::from_be_bytes(raw)
+    } else {
+        u32::from_le_bytes(raw)
+    })
+}
+
+/// Reads the PE, ELF or Mach-O header of `path`. Returns `None` for scripts, universal Mach-O
+/// binaries and machine types pyappify does not know, which are left for the OS to judge.
+pub fn read_executable_target(path: &Path) -> Result<Option<ExecutableTarget>> {
+    let mut file =
+        File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut header = [0u8; 64];
+    let read = file
+        .read(&mut header)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let header = &header[..read];
+
+    if header.starts_with(b"MZ") {
+        let Some(pe_offset) = read_u32(header, 0x3c, false) else {
+            return Ok(None);
+        };
+        let mut pe_header = [0u8; 6];
+        file.seek(SeekFrom::Start(pe_offset as u64))
+            .and_then(|_| file.read_exact(&mut pe_header))
+            .with_context(|| format!("Failed to read the PE header of {}", path.display()))?;
+        if !pe_header.starts_with(b"PE\0\0") {
+            return Ok(None);
+        }
+        return Ok(parse_pe_machine(
+            read_u16(&pe_header, 4, false).unwrap_or(0),
+        ));
+    }
+    Ok(parse_elf_or_macho(header))
+}
+
+fn parse_pe_machine(machine: u16) -> Option<ExecutableTarget> {
+    let arch = match machine {
+        0x014c => "x86",
+        0x8664 => "x86_64",
+        0x01c4 => "arm",
+        0xaa64 => "aarch64",
+        _ => return None,
+    };
+    Some(ExecutableTarget {
+        os: "windows",
+        arch,
+    })
+}
+
+fn parse_elf_or_macho(header: &[u8]) -> Option<ExecutableTarget> {
+    if header.starts_with(b"\x7fELF") {
+        let big_endian = *header.get(5)? == 2;
+        let arch = match read_u16(header, 18, big_endian)? {
+            0x03 => "x86",
+            0x3e => "x86_64",
+            0x28 => "arm",
+            0xb7 => "aarch64",
+            _ => return None,
+        };
+        return Some(ExecutableTarget { os: "linux", arch });
+    }
+    let big_endian = match read_u32(header, 0, false)? {
+        0xfeedface | 0xfeedfacf => false,
+        0xcefaedfe | 0xcffaedfe => true,
+        _ => return None,
+    };
+    let arch = match read_u32(header, 4, big_endian)? {
+        0x0000_0007 => "x86",
+        0x0100_0007 => "x86_64",
+        0x0000_000c => "arm",
+        0x0100_000c => "aarch64",
+        _ => return None,
+    };
+    Some(ExecutableTarget { os: "macos", arch })
+}
+
+/// Whether the host runs `target` natively or through the emulation the OS ships with: 32-bit
+/// x86 on Windows and Linux x86_64, x86 builds on Windows on Arm and x86_64 builds under Rosetta.
+/// macOS has not run 32-bit binaries since Catalina.
+pub fn runs_on_host(target: ExecutableTarget, host_os: &str, host_arch: &str) -> bool {
+    if target.os != host_os {
+        return false;
+    }
+    target.arch == host_arch
+        || (host_os != "macos" && host_arch == "x86_64" && target.arch == "x86")
+        || (host_os == "windows" && host_arch == "aarch64" && target.arch.starts_with("x86"))
+        || (host_os == "macos" && host_arch == "aarch64" && target.arch == "x86_64")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_elf_or_macho, parse_pe_machine, runs_on_host, ExecutableTarget};
+
+    #[test]
+    fn reads_targets_from_headers_and_checks_them_against_the_host() {
+        let mut elf = vec![0x7f, b'E', b'L', b'F', 2, 1, 1, 0];
+        elf.resize(18, 0);
+        elf.extend_from_slice(&0xb7u16.to_le_bytes());
+        let elf_target = parse_elf_or_macho(&elf).unwrap();
+        assert_eq!(
+            elf_target,
+            ExecutableTarget {
+                os: "linux",
+                arch: "aarch64"
+            }
+        );
+        assert!(!runs_on_host(elf_target, "linux", "x86_64"));
+
+        let mut macho = 0xfeedfacfu32.to_le_bytes().to_vec();
+        macho.extend_from_slice(&0x0100_0007u32.to_le_bytes());
+        let macho_target = parse_elf_or_macho(&macho).unwrap();
+        assert_eq!(macho_target.arch, "x86_64");
+        assert!(runs_on_host(macho_target, "macos", "aarch64"));
+        let macho_x86 = ExecutableTarget {
+            os: "macos",
+            arch: "x86",
+        };
+        assert!(!runs_on_host(macho_x86, "macos", "x86_64"));
+        assert!(!runs_on_host(macho_x86, "macos", "aarch64"));
+
+        let pe_target = parse_pe_machine(0x014c).unwrap();
+        assert!(runs_on_host(pe_target, "windows", "x86_64"));
+        assert!(!runs_on_host(pe_target, "linux", "x86_64"));
+
+        assert_eq!(parse_elf_or_macho(b"@echo off\r\n"), None);
+    }
+}
@@ -1,17 +1,381 @@
 // src/command.rs
+use crate::config_manager::{get_suppressed_output, is_pip_verbose};
 use crate::utils::error::Error;
-use crate::{emit_error, emit_info, ensure_some, err};
+use crate::utils::redact::redact_secrets;
+use crate::{emit_error, emit_info, emit_update_info, emit_warn, ensure_some, err};
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet};
 use std::ffi::OsStr;
 use std::process::{ExitStatus, Stdio};
-use tokio::io::AsyncBufReadExt;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
 use tokio::process::Command;
+use tokio::sync::Notify;
+use tokio::time::{Duration, Instant};
 use tracing::{debug, error, info};
 use windows_sys::Win32::UI::Shell::IsUserAnAdmin;
 
+static RUNNING_CHILDREN: Lazy<Mutex<HashMap<String, Vec<u32>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+static CANCEL_REQUESTED: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+static CANCEL_REQUESTED_CHANGED: Notify = Notify::const_new();
+
+/// Marks the app's current operation as cancelled and kills every command spawned through
+/// `run_command_and_stream_output` for it, including their child processes.
+/// Returns whether any process was targeted.
+pub fn request_cancel(app_name: &str) -> bool {
+    CANCEL_REQUESTED
+        .lock()
+        .unwrap()
+        .insert(app_name.to_string());
+    CANCEL_REQUESTED_CHANGED.notify_waiters();
+    let pids = RUNNING_CHILDREN
+        .lock()
+        .unwrap()
+        .get(app_name)
+        .cloned()
+        .unwrap_or_default();
+    for pid in &pids {
+        info!(pid, app_name, "Killing process tree for cancelled command");
+        kill_process_tree(*pid);
+    }
+    !pids.is_empty()
+}
+
+pub fn is_cancel_requested(app_name: &str) -> bool {
+    CANCEL_REQUESTED.lock().unwrap().contains(app_name)
+}
+
+/// Resolves once `request_cancel` has been called for the app.
+pub async fn wait_for_cancel_request(app_name: &str) {
+    loop {
+        let changed = CANCEL_REQUESTED_CHANGED.notified();
+        if is_cancel_requested(app_name) {
+            return;
+        }
+        changed.await;
+    }
+}
+
+pub fn clear_cancel_request(app_name: &str) {
+    CANCEL_REQUESTED.lock().unwrap().remove(app_name);
+}
+
+pub fn ensure_not_cancelled(app_name: &str) -> Result<(), Error> {
+    if is_cancel_requested(app_name) {
+        return Err(err!("Operation for '{}' was cancelled", app_name));
+    }
+    Ok(())
+}
+
+fn register_child(app_name: &str, pid: u32) {
+    RUNNING_CHILDREN
+        .lock()
+        .unwrap()
+        .entry(app_name.to_string())
+        .or_default()
+        .push(pid);
+}
+
+fn unregister_child(app_name: &str, pid: u32) {
+    let mut running = RUNNING_CHILDREN.lock().unwrap();
+    if let Some(pids) = running.get_mut(app_name) {
+        pids.retain(|p| *p != pid);
+        if pids.is_empty() {
+            running.remove(app_name);
+        }
+    }
+}
+
+#[cfg(windows)]
+fn kill_process_tree(pid: u32) {
+    use std::os::windows::process::CommandExt;
+    let result = std::process::Command::new("taskkill")
+        .creation_flags(0x08000000)
+        .args(["/F", "/T", "/PID", &pid.to_string()])
+        .status();
+    if let Err(e) = result {
+        error!(pid, "Failed to run taskkill: {}", e);
+    }
+}
+
+#[cfg(not(windows))]
+fn kill_process_tree(pid: u32) {
+    let result = std::process::Command::new("pkill")
+        .args(["-KILL", "-P", &pid.to_string()])
+        .status()
+        .and_then(|_| {
+            std::process::Command::new("kill")
+                .args(["-KILL", &pid.to_string()])
+                .status()
+        });
+    if let Err(e) = result {
+        error!(pid, "Failed to kill process tree: {}", e);
+    }
+}
+
 pub async fn run_command_and_stream_output(
+    command: Command,
+    app_name: &str,
+    command_description: &str,
+) -> Result<ExitStatus, Error> {
+    let status = run_command_and_stream_status(command, app_name, command_description).await?;
+
+    if !status.success() {
+        if is_cancel_requested(app_name) {
+            return Err(err!("Command cancelled ({})", command_description));
+        }
+        return Err(err!("Command failed ({}): {}", command_description, status));
+    }
+
+    Ok(status)
+}
+
+/// How long stdout lines are held back so they can be emitted as one batch.
+const OUTPUT_FLUSH_INTERVAL: Duration = Duration::from_millis(200);
+const MAX_BATCHED_LINES: usize = 100;
+/// Minimum time between two emitted states of a carriage-return progress line.
+const PROGRESS_UPDATE_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Reads the next line or carriage-return progress state into `buffer`, without its terminator.
+/// Returns the terminator (`\r\n` counts as `\n`), or `None` at the end of the stream. Cancel
+/// safe: nothing is consumed without being stored in `buffer`.
+async fn read_segment<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+    buffer: &mut Vec<u8>,
+) -> std::io::Result<Option<u8>> {
+    loop {
+        let available = reader.fill_buf().await?;
+        if available.is_empty() {
+            return Ok((!buffer.is_empty()).then_some(b'\n'));
+        }
+        match available.iter().position(|b| *b == b'\n' || *b == b'\r') {
+            Some(i) if available[i] == b'\r' && available.get(i + 1) == Some(&b'\n') => {
+                buffer.extend_from_slice(&available[..i]);
+                reader.consume(i + 2);
+                return Ok(Some(b'\n'));
+            }
+            Some(i) => {
+                let terminator = available[i];
+                buffer.extend_from_slice(&available[..i]);
+                reader.consume(i + 1);
+                return Ok(Some(terminator));
+            }
+            None => {
+                let len = available.len();
+                buffer.extend_from_slice(available);
+                reader.consume(len);
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Emission {
+    Line(String),
+    Update(String),
+}
+
+/// Turns a command's output into few events: consecutive lines are batched, and the states of a
+/// carriage-return progress bar collapse into one line that is updated in place.
+#[derive(Default)]
+struct OutputCoalescer {
+    pending_lines: Vec<String>,
+    batch_started: Option<Instant>,
+    progress_shown: bool,
+    unshown_progress: Option<String>,
+    last_progress_emitted: Option<Instant>,
+}
+
+impl OutputCoalescer {
+    fn push_line(&mut self, line: String, now: Instant) -> Vec<Emission> {
+        if self.progress_shown {
+            // The newline ends the progress bar; its final state replaces the updating line.
+            self.progress_shown = false;
+            let final_state = if line.trim().is_empty() {
+                self.unshown_progress.take()
+            } else {
+                self.unshown_progress = None;
+                Some(line)
+            };
+            return final_state.map(Emission::Update).into_iter().collect();
+        }
+        self.pending_lines.push(line);
+        self.batch_started.get_or_insert(now);
+        if self.pending_lines.len() >= MAX_BATCHED_LINES {
+            return self.flush();
+        }
+        self.tick(now)
+    }
+
+    fn push_progress(&mut self, state: String, now: Instant) -> Vec<Emission> {
+        if state.trim().is_empty() {
+            return Vec::new();
+        }
+        let mut emissions = self.flush();
+        if !self.progress_shown {
+            self.progress_shown = true;
+            self.last_progress_emitted = Some(now);
+            emissions.push(Emission::Line(state));
+        } else {
+            self.unshown_progress = Some(state);
+            emissions.extend(self.tick(now));
+        }
+        emissions
+    }
+
+    /// Emits what has waited long enough: the line batch and the newest progress state.
+    fn tick(&mut self, now: Instant) -> Vec<Emission> {
+        let mut emissions = Vec::new();
+        if self
+            .batch_started
+            .is_some_and(|started| now - started >= OUTPUT_FLUSH_INTERVAL)
+        {
+            emissions.extend(self.flush());
+        }
+        if self.unshown_progress.is_some()
+            && self
+                .last_progress_emitted
+                .is_none_or(|emitted| now - emitted >= PROGRESS_UPDATE_INTERVAL)
+        {
+            self.last_progress_emitted = Some(now);
+            emissions.extend(self.unshown_progress.take().map(Emission::Update));
+        }
+        emissions
+    }
+
+    fn next_deadline(&self) -> Option<Instant> {
+        let batch_deadline = self
+            .batch_started
+            .map(|started| started + OUTPUT_FLUSH_INTERVAL);
+        let progress_deadline = self
+            .unshown_progress
+            .as_ref()
+            .and(self.last_progress_emitted)
+            .map(|emitted| emitted + PROGRESS_UPDATE_INTERVAL);
+        batch_deadline.into_iter().chain(progress_deadline).min()
+    }
+
+    fn flush(&mut self) -> Vec<Emission> {
+        self.batch_started = None;
+        if self.pending_lines.is_empty() {
+            return Vec::new();
+        }
+        vec![Emission::Line(
+            std::mem::take(&mut self.pending_lines).join("\n"),
+        )]
+    }
+
+    fn finish(&mut self) -> Vec<Emission> {
+        let mut emissions = self.flush();
+        emissions.extend(self.unshown_progress.take().map(Emission::Update));
+        self.progress_shown = false;
+        emissions
+    }
+}
+
+fn emit_output(app_name: &str, emissions: Vec<Emission>) {
+    for emission in emissions {
+        match emission {
+            Emission::Line(text) => emit_info!(app_name, "{}", text),
+            Emission::Update(text) => emit_update_info!(app_name, "{}", text),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum StderrLevel {
+    Suppressed,
+    Warning,
+    Error,
+}
+
+/// Stderr carries warnings and notices as well as failures; only the latter are emitted as errors.
+/// With `show_suppressed_errors`, a line matching `suppressed_output` is still shown when it looks
+/// like an error.
+fn classify_stderr_line(
+    line: &str,
+    suppressed_output: &[String],
+    show_suppressed_errors: bool,
+) -> StderrLevel {
+    const ERROR_MARKERS: [&str; 4] = ["error", "traceback", "exception", "failed"];
+    const WARNING_MARKERS: [&str; 3] = ["warning", "deprecat", "[notice]"];
+
+    if line.trim().is_empty() {
+        return StderrLevel::Suppressed;
+    }
+    let lowercase = line.to_lowercase();
+    let level = if !ERROR_MARKERS
+        .iter()
+        .any(|marker| lowercase.contains(marker))
+        && WARNING_MARKERS
+            .iter()
+            .any(|marker| lowercase.contains(marker))
+    {
+        StderrLevel::Warning
+    } else {
+        StderrLevel::Error
+    };
+    let matches_suppressed = suppressed_output
+        .iter()
+        .any(|pattern| line.contains(pattern.as_str()));
+    if matches_suppressed && !(show_suppressed_errors && level == StderrLevel::Error) {
+        StderrLevel::Suppressed
+    } else {
+        level
+    }
+}
+
+/// Like `run_command_and_stream_output`, but hands back the exit status of a command that ran
+/// and exited unsuccessfully instead of turning it into an error.
+pub async fn run_command_and_stream_status(
+    command: Command,
+    app_name: &str,
+    command_description: &str,
+) -> Result<ExitStatus, Error> {
+    stream_command(
+        command,
+        app_name,
+        command_description,
+        true,
+        CREATE_NO_WINDOW,
+    )
+    .await
+}
+
+/// Streams the output of the app's own launch. Unlike setup commands it is not killed by
+/// `request_cancel`, so cancelling a setup never stops the running app. With `show_console` the
+/// app gets a visible console window, while its output is still streamed to the log.
+pub async fn run_app_and_stream_status(
+    command: Command,
+    app_name: &str,
+    command_description: &str,
+    show_console: bool,
+) -> Result<ExitStatus, Error> {
+    let creation_flags = if show_console {
+        CREATE_NEW_CONSOLE
+    } else {
+        CREATE_NO_WINDOW
+    };
+    stream_command(
+        command,
+        app_name,
+        command_description,
+        false,
+        creation_flags,
+    )
+    .await
+}
+
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+const CREATE_NEW_CONSOLE: u32 = 0x00000010;
+
+async fn stream_command(
     mut command: Command,
     app_name: &str,
     command_description: &str,
+    cancellable: bool,
+    creation_flags: u32,
 ) -> Result<ExitStatus, Error> {
     emit_info!(
         app_name,
@@ -20,7 +384,7 @@ pub async fn run_command_and_stream_output(
         command
     );
 
-    command.creation_flags(0x08000000);
+    command.creation_flags(creation_flags);
     command.stdout(Stdio::piped());
     command.stderr(Stdio::piped());
 
@@ -30,11 +394,15 @@ pub async fn run_command_and_stream_output(
         err!(msg)
     })?;
 
-    let child_pid = child
-        .id()
+    let child_id = child.id();
+    let child_pid = child_id
         .map(|id| id.to_string())
         .unwrap_or_else(|| "N/A".to_string());
     info!(pid = %child_pid, cmd_desc = %command_description, "Command spawned");
+    let registered_pid = child_id.filter(|_| cancellable);
+    if let Some(pid) = registered_pid {
+        register_child(app_name, pid);
+    }
 
     let stdout = ensure_some!(
         child.stdout.take(),
@@ -60,14 +428,38 @@ pub async fn run_command_and_stream_output(
     let mut stderr_buf_reader = tokio::io::BufReader::new(stderr);
 
     let app_name_for_stdout = app_name.to_string();
+    // Lines are read as bytes and decoded lossily: an app writing in the system code page
+    // (see the profile's io_encoding) must not end the stream on its first non-UTF-8 line.
+    // Verbose pip output is coalesced so it does not flood the UI with one event per line.
+    // The stderr task shares the coalescer to flush it first, keeping both streams in order.
+    let coalescer = Arc::new(Mutex::new(OutputCoalescer::default()));
+    let stdout_coalescer = coalescer.clone();
     let stdout_task = tokio::spawn(async move {
-        let mut buffer = String::new();
+        let mut buffer = Vec::new();
         loop {
-            match stdout_buf_reader.read_line(&mut buffer).await {
-                Ok(0) => break,
-                Ok(_) => {
-                    emit_info!(app_name_for_stdout, "{}", buffer.as_str());
+            let deadline = stdout_coalescer.lock().unwrap().next_deadline();
+            let segment = tokio::select! {
+                segment = read_segment(&mut stdout_buf_reader, &mut buffer) => segment,
+                _ = tokio::time::sleep_until(deadline.unwrap_or_else(Instant::now)), if deadline.is_some() => {
+                    let emissions = stdout_coalescer.lock().unwrap().tick(Instant::now());
+                    emit_output(&app_name_for_stdout, emissions);
+                    continue;
+                }
+            };
+            match segment {
+                Ok(None) => break,
+                Ok(Some(terminator)) => {
+                    let text = String::from_utf8_lossy(&buffer).into_owned();
                     buffer.clear();
+                    let emissions = {
+                        let mut coalescer = stdout_coalescer.lock().unwrap();
+                        if terminator == b'\r' {
+                            coalescer.push_progress(text, Instant::now())
+                        } else {
+                            coalescer.push_line(text, Instant::now())
+                        }
+                    };
+                    emit_output(&app_name_for_stdout, emissions);
                 }
                 Err(e) => {
                     emit_error!(app_name_for_stdout, "Error reading stdout line: {}", e);
@@ -75,24 +467,42 @@ pub async fn run_command_and_stream_output(
                 }
             }
         }
+        let emissions = stdout_coalescer.lock().unwrap().finish();
+        emit_output(&app_name_for_stdout, emissions);
     });
 
     let app_name_for_stderr = app_name.to_string();
+    let suppressed_output = get_suppressed_output();
+    let show_suppressed_errors = is_pip_verbose();
     let stderr_task = tokio::spawn(async move {
-        let mut buffer = String::new();
+        let mut buffer = Vec::new();
         loop {
-            match stderr_buf_reader.read_line(&mut buffer).await {
+            match stderr_buf_reader.read_until(b'\n', &mut buffer).await {
                 Ok(0) => break,
                 Ok(_) => {
-                    let err_string = buffer.to_string();
+                    let err_string = String::from_utf8_lossy(&buffer).into_owned();
                     buffer.clear();
-                    if !err_string.trim().is_empty()
-                        && !err_string.contains("A new release of pip is available")
-                        && !err_string.contains("[notice] To update, run")
-                    {
-                        emit_error!(app_name_for_stderr, "{}", err_string);
-                    } else {
-                        debug!("not emitting black listed stderr {}", err_string);
+                    let level = classify_stderr_line(
+                        &err_string,
+                        &suppressed_output,
+                        show_suppressed_errors,
+                    );
+                    if level != StderrLevel::Suppressed {
+                        // Held-back stdout came first; a progress bar ends here, so its next
+                        // state does not overwrite this line.
+                        let emissions = coalescer.lock().unwrap().finish();
+                        emit_output(&app_name_for_stderr, emissions);
+                    }
+                    match level {
+                        StderrLevel::Suppressed => {
+                            debug!("not emitting suppressed stderr {}", err_string);
+                        }
+                        StderrLevel::Warning => {
+                            emit_warn!(app_name_for_stderr, "{}", err_string);
+                        }
+                        StderrLevel::Error => {
+                            emit_error!(app_name_for_stderr, "{}", err_string);
+                        }
                     }
                 }
                 Err(e) => {
@@ -103,16 +513,16 @@ pub async fn run_command_and_stream_output(
         }
     });
 
-    let status = child.wait().await?;
+    let wait_result = child.wait().await;
+    if let Some(pid) = registered_pid {
+        unregister_child(app_name, pid);
+    }
+    let status = wait_result?;
 
     if let Err(e) = tokio::try_join!(stdout_task, stderr_task) {
         error!(error = %e, cmd_desc = %command_description, "Log reading task encountered an error. This does not necessarily mean the command itself failed.");
     }
 
-    if !status.success() {
-        return Err(err!("Command failed ({}): {}", command_description, status));
-    }
-
     Ok(status)
 }
 
@@ -135,7 +545,44 @@ pub fn command_to_string(command: &std::process::Command) -> String {
             command_string.push_str(arg);
         }
     }
-    command_string
+    redact_secrets(&command_string).into_owned()
+}
+
+/// Splits a command line from `pyappify.yml` into its program and arguments. Whitespace
+/// separates arguments unless it is inside single or double quotes, which are removed.
+/// Backslashes are kept as they are, so Windows paths need no escaping.
+pub fn split_command_line(command: &str) -> Result<Vec<String>, Error> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut in_part = false;
+    let mut quote = None;
+    for c in command.chars() {
+        match quote {
+            Some(open) if c == open => quote = None,
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                in_part = true;
+            }
+            None if c.is_whitespace() => {
+                if in_part {
+                    parts.push(std::mem::take(&mut current));
+                    in_part = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_part = true;
+            }
+        }
+    }
+    if let Some(open) = quote {
+        return Err(err!("Unterminated {} in command: {}", open, command));
+    }
+    if in_part {
+        parts.push(current);
+    }
+    Ok(parts)
 }
 
 #[cfg(windows)]
@@ -161,3 +608,180 @@ pub async fn is_admin() -> bool {
     }
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        classify_stderr_line, clear_cancel_request, read_segment, request_cancel,
+        split_command_line, wait_for_cancel_request, Emission, OutputCoalescer, StderrLevel,
+        OUTPUT_FLUSH_INTERVAL,
+    };
+    use tokio::time::{Duration, Instant};
+
+    #[tokio::test]
+    async fn wakes_cancel_waiters_when_cancel_is_requested() {
+        let app_name = "cancel-waiter-test";
+        clear_cancel_request(app_name);
+        let waiter = tokio::spawn(async move { wait_for_cancel_request(app_name).await });
+        tokio::task::yield_now().await;
+        assert!(!waiter.is_finished());
+
+        request_cancel(app_name);
+        tokio::time::timeout(Duration::from_secs(5), waiter)
+            .await
+            .expect("waiter should wake on cancel")
+            .unwrap();
+        clear_cancel_request(app_name);
+    }
+
+    #[tokio::test]
+    async fn splits_output_on_newlines_and_carriage_returns() {
+        let mut reader: &[u8] = b"one\r\n 10%\r 50%\rdone\ntail";
+        let mut buffer = Vec::new();
+        let mut segments = Vec::new();
+        while let Some(terminator) = read_segment(&mut reader, &mut buffer).await.unwrap() {
+            segments.push((String::from_utf8(buffer.clone()).unwrap(), terminator));
+            buffer.clear();
+        }
+        assert_eq!(
+            segments,
+            vec![
+                ("one".to_string(), b'\n'),
+                (" 10%".to_string(), b'\r'),
+                (" 50%".to_string(), b'\r'),
+                ("done".to_string(), b'\n'),
+                ("tail".to_string(), b'\n'),
+            ]
+        );
+    }
+
+    #[test]
+    fn splits_command_lines_with_quoted_paths() {
+        assert_eq!(
+            split_command_line(
+                r#""C:\Program Files\App\python.exe" manage.py migrate --name='a b' "" "#
+            )
+            .unwrap(),
+            vec![
+                r"C:\Program Files\App\python.exe",
+                "manage.py",
+                "migrate",
+                "--name=a b",
+                ""
+            ]
+        );
+        assert!(split_command_line("  ").unwrap().is_empty());
+        assert!(split_command_line("python \"unterminated").is_err());
+    }
+
+    #[test]
+    fn batches_lines_and_collapses_progress_states() {
+        let start = Instant::now();
+        let mut coalescer = OutputCoalescer::default();
+        assert!(coalescer.push_line("Collecting a".into(), start).is_empty());
+        assert!(coalescer.push_line("Collecting b".into(), start).is_empty());
+        assert_eq!(
+            coalescer.push_progress(" 10%".into(), start),
+            vec![
+                Emission::Line("Collecting a\nCollecting b".into()),
+                Emission::Line(" 10%".into())
+            ]
+        );
+        for percent in 11..99 {
+            let at = start + Duration::from_millis(percent);
+            assert!(coalescer
+                .push_progress(format!(" {}%", percent), at)
+                .is_empty());
+        }
+        assert_eq!(
+            coalescer.push_line(String::new(), start + Duration::from_millis(100)),
+            vec![Emission::Update(" 98%".into())]
+        );
+
+        assert!(coalescer.push_line("Installing".into(), start).is_empty());
+        assert_eq!(
+            coalescer.tick(start + OUTPUT_FLUSH_INTERVAL),
+            vec![Emission::Line("Installing".into())]
+        );
+        assert!(coalescer.finish().is_empty());
+    }
+
+    #[test]
+    fn finish_flushes_held_lines_and_ends_the_progress_bar() {
+        let start = Instant::now();
+        let mut coalescer = OutputCoalescer::default();
+        assert_eq!(
+            coalescer.push_progress(" 10%".into(), start),
+            vec![Emission::Line(" 10%".into())]
+        );
+        assert!(coalescer
+            .push_progress(" 20%".into(), start + Duration::from_millis(10))
+            .is_empty());
+        assert_eq!(coalescer.finish(), vec![Emission::Update(" 20%".into())]);
+
+        // The next state starts a new line instead of updating whatever was emitted in between.
+        assert_eq!(
+            coalescer.push_progress(" 30%".into(), start + Duration::from_millis(20)),
+            vec![Emission::Line(" 30%".into())]
+        );
+    }
+
+    #[test]
+    fn classifies_pip_stderr_lines() {
+        let suppressed = vec!["A new release of pip is available".to_string()];
+        assert_eq!(
+            classify_stderr_line(
+                "[notice] A new release of pip is available: 24.0 -> 25.1",
+                &suppressed,
+                false
+            ),
+            StderrLevel::Suppressed
+        );
+        assert_eq!(
+            classify_stderr_line(
+                "DEPRECATION: Building 'foo' using the legacy setup.py bdist_wheel mechanism",
+                &suppressed,
+                false
+            ),
+            StderrLevel::Warning
+        );
+        assert_eq!(
+            classify_stderr_line(
+                "ERROR: Could not find a version that satisfies the requirement foo==9.9",
+                &suppressed,
+                false
+            ),
+            StderrLevel::Error
+        );
+        assert_eq!(
+            classify_stderr_line(
+                "[notice] To update, run: python -m pip install -U pip",
+                &[],
+                false
+            ),
+            StderrLevel::Warning
+        );
+    }
+
+    #[test]
+    fn verbose_pip_keeps_suppressing_notices_but_shows_errors() {
+        let suppressed = vec![
+            "A new release of pip is available".to_string(),
+            "Could not fetch URL".to_string(),
+        ];
+        let notice = "[notice] A new release of pip is available: 24.0 -> 25.1";
+        let failure = "Could not fetch URL https://pypi.org/simple/foo/: connection error";
+        assert_eq!(
+            classify_stderr_line(notice, &suppressed, true),
+            StderrLevel::Suppressed
+        );
+        assert_eq!(
+            classify_stderr_line(failure, &suppressed, false),
+            StderrLevel::Suppressed
+        );
+        assert_eq!(
+            classify_stderr_line(failure, &suppressed, true),
+            StderrLevel::Error
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use crate::utils::command::new_cmd;
+use crate::utils::path::path_to_abs;
 use anyhow::{Context, Result};
+use chrono::Utc;
 use std::path::{Path, PathBuf};
 use std::{fs, io};
-use tracing::{debug, info};
+use sysinfo::Disks;
+use tracing::{debug, info, warn};
 use walkdir::WalkDir;
 
 pub fn copy_dir_recursive_excluding_sync(
@@ -33,7 +36,13 @@ pub fn copy_dir_recursive_excluding_sync(
     Ok(())
 }
 
-pub fn sync_delete_extra_files(working_dir: &Path, repo_dir: &Path) -> io::Result<()> {
+/// Deletes the working dir files the repo no longer has, except the `preserve` paths given
+/// relative to `working_dir`.
+pub fn sync_delete_extra_files(
+    working_dir: &Path,
+    repo_dir: &Path,
+    preserve: &[PathBuf],
+) -> io::Result<()> {
     let mut paths_to_delete: Vec<PathBuf> = Vec::new();
 
     let walker = WalkDir::new(working_dir).into_iter().filter_entry(|entry| {
@@ -50,6 +59,9 @@ pub fn sync_delete_extra_files(working_dir: &Path, repo_dir: &Path) -> io::Resul
         if relative_path.as_os_str().is_empty() && working_path != working_dir {
             return false; // Should not happen if strip_prefix is correct
         }
+        if is_preserved(relative_path, preserve) {
+            return false;
+        }
         let repo_equivalent_path = repo_dir.join(relative_path);
 
         if entry.file_type().is_dir() {
@@ -102,7 +114,81 @@ pub fn sync_delete_extra_files(working_dir: &Path, repo_dir: &Path) -> io::Resul
     Ok(())
 }
 
+/// Whether `relative` is one of the `preserve` paths or lies inside one of them.
+pub fn is_preserved(relative: &Path, preserve: &[PathBuf]) -> bool {
+    preserve.iter().any(|kept| relative.starts_with(kept))
+}
+
+/// Deletes everything under `dir` except the `preserve` paths, given relative to `dir`, and the
+/// directories leading to them. Links are removed without following them.
+pub fn delete_dir_contents_except(dir: &Path, preserve: &[PathBuf]) -> io::Result<()> {
+    delete_contents_except(dir, Path::new(""), preserve)
+}
+
+fn delete_contents_except(dir: &Path, relative_dir: &Path, preserve: &[PathBuf]) -> io::Result<()> {
+    for entry in fs::read_dir(dir.join(relative_dir))? {
+        let entry = entry?;
+        let relative = relative_dir.join(entry.file_name());
+        if is_preserved(&relative, preserve) {
+            continue;
+        }
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        if file_type.is_symlink() {
+            remove_link(&path)?;
+        } else if file_type.is_dir() {
+            if preserve.iter().any(|kept| kept.starts_with(&relative)) {
+                delete_contents_except(dir, &relative, preserve)?;
+            } else {
+                fs::remove_dir_all(&path)?;
+            }
+        } else {
+            fs::remove_file(&path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Whether `path` itself is a symlink or, on Windows, a directory junction.
+pub fn is_link(path: &Path) -> bool {
+    fs::symlink_metadata(path)
+        .map(|metadata| metadata.file_type().is_symlink())
+        .unwrap_or(false)
+}
+
+/// Removes the link at `path` without touching what it points to. Windows needs `remove_dir`
+/// for directory symlinks and junctions, everything else `remove_file`.
+fn remove_link(path: &Path) -> io::Result<()> {
+    fs::remove_file(path).or_else(|_| fs::remove_dir(path))
+}
+
+/// Unlinks every symlink and junction below `path` so a recursive delete that follows them,
+/// like `rd /S /Q` on some Windows versions, can only reach the tree itself.
+#[cfg(windows)]
+fn remove_links_under(path: &Path) {
+    for entry in WalkDir::new(path)
+        .min_depth(1)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path_is_symlink())
+    {
+        if let Err(e) = remove_link(entry.path()) {
+            warn!("Failed to unlink {}: {}", entry.path().display(), e);
+        }
+    }
+}
+
+/// Deletes the directory at `working_dir_path`. A symlink or junction there is unlinked, and
+/// links inside the tree are never followed, so data they point to survives.
 pub async fn delete_dir_if_exist(working_dir_path: &Path) -> Result<()> {
+    if is_link(working_dir_path) {
+        info!(
+            "Unlinking {} instead of deleting its target",
+            working_dir_path.display()
+        );
+        return remove_link(working_dir_path)
+            .with_context(|| format!("Failed to unlink {}", working_dir_path.display()));
+    }
     let result = fs::remove_dir_all(working_dir_path);
 
     info!(
@@ -118,6 +204,7 @@ pub async fn delete_dir_if_exist(working_dir_path: &Path) -> Result<()> {
 
         #[cfg(windows)]
         {
+            remove_links_under(working_dir_path);
             let status = new_cmd("cmd")
                 .args([
                     "/C",
@@ -140,3 +227,162 @@ pub async fn delete_dir_if_exist(working_dir_path: &Path) -> Result<()> {
 
     result.with_context(|| format!("Failed to remove dir {}", working_dir_path.display()))
 }
+
+/// Total size of the files under `path`; unreadable entries are skipped.
+pub fn dir_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// Copies `path` to `backups/<file name>.<timestamp>` next to it and deletes all but the `keep`
+/// newest backups of that file. Does nothing when `path` does not exist or `keep` is 0.
+pub fn backup_file(path: &Path, keep: usize) -> Result<Option<PathBuf>> {
+    if keep == 0 || !path.is_file() {
+        return Ok(None);
+    }
+    let file_name = path
+        .file_name()
+        .with_context(|| format!("{} has no file name", path.display()))?
+        .to_string_lossy()
+        .into_owned();
+    let backup_dir = path.parent().unwrap_or(Path::new(".")).join("backups");
+    fs::create_dir_all(&backup_dir)
+        .with_context(|| format!("Failed to create {}", backup_dir.display()))?;
+    let backup_path = backup_dir.join(format!(
+        "{}.{}",
+        file_name,
+        Utc::now().format("%Y%m%d-%H%M%S%.3f")
+    ));
+    fs::copy(path, &backup_path)
+        .with_context(|| format!("Failed to back up {}", path.display()))?;
+
+    let prefix = format!("{}.", file_name);
+    let mut backups: Vec<PathBuf> = fs::read_dir(&backup_dir)
+        .with_context(|| format!("Failed to list {}", backup_dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|backup| {
+            backup
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with(&prefix))
+        })
+        .collect();
+    backups.sort();
+    let excess = backups.len().saturating_sub(keep);
+    for old_backup in &backups[..excess] {
+        if let Err(e) = fs::remove_file(old_backup) {
+            warn!(
+                "Failed to remove old backup {}: {}",
+                old_backup.display(),
+                e
+            );
+        }
+    }
+    Ok(Some(backup_path))
+}
+
+pub fn get_available_space(path: &Path) -> Option<u64> {
+    let abs_path = PathBuf::from(path_to_abs(path));
+    let disks = Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|disk| abs_path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| disk.available_space())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{backup_file, delete_dir_contents_except, delete_dir_if_exist, is_link};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    #[cfg(unix)]
+    fn link_dir(target: &Path, link: &Path) {
+        std::os::unix::fs::symlink(target, link).unwrap();
+    }
+
+    #[cfg(windows)]
+    fn link_dir(target: &Path, link: &Path) {
+        let status = std::process::Command::new("cmd")
+            .arg("/C")
+            .arg("mklink")
+            .arg("/J")
+            .arg(link)
+            .arg(target)
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+
+    #[tokio::test]
+    async fn deletes_links_without_touching_their_targets() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let target = dir.join("data");
+        fs::create_dir_all(&target).unwrap();
+        fs::write(target.join("keep.txt"), "keep").unwrap();
+
+        let linked_app = dir.join("linked-app");
+        link_dir(&target, &linked_app);
+        assert!(is_link(&linked_app));
+        delete_dir_if_exist(&linked_app).await.unwrap();
+        assert!(!linked_app.exists());
+
+        let app = dir.join("app");
+        fs::create_dir_all(&app).unwrap();
+        link_dir(&target, &app.join("working"));
+        delete_dir_if_exist(&app).await.unwrap();
+        assert!(!app.exists());
+
+        assert_eq!(fs::read_to_string(target.join("keep.txt")).unwrap(), "keep");
+    }
+
+    #[test]
+    fn deletes_everything_but_the_preserved_paths() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        fs::create_dir_all(dir.join("saves")).unwrap();
+        fs::create_dir_all(dir.join("config")).unwrap();
+        fs::write(dir.join("main.py"), "print()").unwrap();
+        fs::write(dir.join("saves/slot1"), "save").unwrap();
+        fs::write(dir.join("config/user.json"), "{}").unwrap();
+        fs::write(dir.join("config/defaults.json"), "{}").unwrap();
+
+        delete_dir_contents_except(
+            &dir,
+            &[PathBuf::from("saves"), PathBuf::from("config/user.json")],
+        )
+        .unwrap();
+
+        assert!(!dir.join("main.py").exists());
+        assert!(!dir.join("config/defaults.json").exists());
+        assert_eq!(fs::read_to_string(dir.join("saves/slot1")).unwrap(), "save");
+        assert!(dir.join("config/user.json").exists());
+    }
+
+    #[test]
+    fn keeps_only_the_newest_backups() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let path = dir.join("app.json");
+        for version in 0..4 {
+            fs::write(&path, format!("{{\"version\": {}}}", version)).unwrap();
+            backup_file(&path, 2).unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        let mut backups: Vec<String> = fs::read_dir(dir.join("backups"))
+            .unwrap()
+            .map(|entry| fs::read_to_string(entry.unwrap().path()).unwrap())
+            .collect();
+        backups.sort();
+        assert_eq!(backups, vec!["{\"version\": 2}", "{\"version\": 3}"]);
+        assert_eq!(backup_file(&dir.join("missing.json"), 2).unwrap(), None);
+    }
+}
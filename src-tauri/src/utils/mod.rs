@@ -1,5 +1,6 @@
 // src/utils/mod.rs
 
+pub mod binary;
 pub mod command;
 pub mod defender;
 pub mod error;
@@ -8,4 +9,6 @@ pub mod locale;
 pub mod logger;
 pub mod path;
 pub mod process;
+pub mod redact;
+pub mod volume;
 pub mod window;
@@ -1,4 +1,4 @@
-use lazy_static::lazy_static;
+use once_cell::sync::OnceCell;
 use std::env;
 use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Manager};
@@ -8,21 +8,44 @@ const APPS_DIR: &str = "apps";
 pub const PYTHON_ROOT_DIR: &str = "python";
 const WORKING_DIR_NAME: &str = "working";
 
-lazy_static! {
-    static ref CWD: PathBuf = env::current_dir().expect("Failed to get current directory");
+/// Directory the data tree, pip cache and defender exclusion live under. Fixed on first use.
+static ROOT: OnceCell<PathBuf> = OnceCell::new();
+
+#[cfg(not(test))]
+fn default_root() -> PathBuf {
+    env::current_dir().expect("Failed to get current directory")
+}
+
+/// Tests never touch the real data dir; each test process gets its own tree.
+#[cfg(test)]
+fn default_root() -> PathBuf {
+    env::temp_dir().join(format!("pyappify-test-root-{}", std::process::id()))
+}
+
+fn root() -> &'static PathBuf {
+    ROOT.get_or_init(default_root)
+}
+
+/// Points every path helper at `root` instead of the per-process test dir. Must run before any
+/// path is resolved; returns the root already in use otherwise.
+#[cfg(test)]
+pub fn set_root(root: PathBuf) -> Result<(), PathBuf> {
+    ROOT.set(root)
+        .map_err(|_| ROOT.get().cloned().unwrap_or_default())
 }
+
 pub fn get_log_dir() -> PathBuf {
-    PathBuf::from(BASE_DIR).join("logs")
+    get_base_dir().join("logs")
 }
 fn get_base_dir() -> PathBuf {
-    CWD.join(BASE_DIR)
+    root().join(BASE_DIR)
 }
 pub fn get_python_dir(app_name: &str) -> PathBuf {
     get_app_base_path(app_name).join(PYTHON_ROOT_DIR)
 }
 
 pub fn get_cwd() -> PathBuf {
-    CWD.clone()
+    root().clone()
 }
 
 pub fn get_python_exe(app_name: &str, use_pythonw: bool) -> PathBuf {
@@ -34,6 +57,11 @@ pub fn get_python_exe(app_name: &str, use_pythonw: bool) -> PathBuf {
     }
 }
 
+/// Names of the apps added with `add_app`, which are kept next to the embedded app.
+pub fn get_registered_apps_path() -> PathBuf {
+    get_base_dir().join("registered_apps.json")
+}
+
 pub fn get_apps_dir() -> PathBuf {
     get_base_dir().join(APPS_DIR)
 }
@@ -48,8 +76,38 @@ pub fn get_app_base_path(app_name: &str) -> PathBuf {
 pub fn get_app_working_dir_path(app_name: &str) -> PathBuf {
     get_app_base_path(app_name).join(WORKING_DIR_NAME)
 }
+
+pub fn get_app_status_file_path(app_name: &str) -> PathBuf {
+    get_app_base_path(app_name).join("status.json")
+}
+
+/// Written once an `admin_first_run` app has exited successfully from its elevated launch.
+pub fn get_admin_first_run_marker_path(app_name: &str) -> PathBuf {
+    get_app_base_path(app_name).join(".admin_first_run_done")
+}
+
+/// JSON lines the running app appends for the UI, named by PYAPPIFY_EVENT_SOCKET.
+pub fn get_app_events_file_path(app_name: &str) -> PathBuf {
+    get_app_base_path(app_name).join("events.jsonl")
+}
+
+/// Records which pyappify process started the app, see `start_app`.
+pub fn get_app_owner_file_path(app_name: &str) -> PathBuf {
+    get_app_base_path(app_name).join("owner.json")
+}
+
+/// Sizes and hashes of the working dir files as last synced from the repo, see `verify_working_dir`.
+pub fn get_app_manifest_path(app_name: &str) -> PathBuf {
+    get_app_base_path(app_name).join("manifest.json")
+}
+
+/// Pinned requirements exported from the app's poetry.lock or Pipfile.lock, see `lockfile`.
+pub fn get_app_lock_requirements_path(app_name: &str) -> PathBuf {
+    get_app_base_path(app_name).join("lock_requirements.txt")
+}
+
 pub fn get_pip_cache_dir() -> PathBuf {
-    CWD.join("cache").join("pip")
+    root().join("cache").join("pip")
 }
 
 pub fn get_config_dir() -> PathBuf {
@@ -89,3 +147,19 @@ pub fn path_to_abs(path: &Path) -> String {
     let path_cow = path.to_string_lossy();
     strip_extended_path_prefix(&path_cow)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{get_app_base_path, get_cwd, get_log_dir, get_pip_cache_dir, set_root};
+    use std::env;
+
+    #[test]
+    fn resolves_every_path_under_the_root() {
+        let root = get_cwd();
+        assert!(root.starts_with(env::temp_dir()));
+        assert!(get_app_base_path("sample").starts_with(&root));
+        assert!(get_pip_cache_dir().starts_with(&root));
+        assert!(get_log_dir().starts_with(&root));
+        assert_eq!(set_root(env::temp_dir().join("other")), Err(root));
+    }
+}
@@ -1,9 +1,18 @@
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::env;
+use std::ffi::{OsStr, OsString};
 use std::path::{Path, PathBuf};
 use std::process::Command as StdCommand;
+use std::sync::Mutex;
 use sysinfo::{Pid, Process, System};
 use tokio::process::Command as TokioCommand;
 
-pub const PYTHON_ENVS_TO_REMOVE: [&str; 13] = [
+/// PIDs of elevated app launches. An unelevated launcher cannot read their exe or cwd, so they
+/// are never found by scanning for processes in the app dir.
+static ELEVATED_PIDS: Lazy<Mutex<HashMap<String, u32>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+pub const PYTHON_ENVS_TO_REMOVE: [&str; 14] = [
     "PYTHONHOME",
     "PYTHONSTARTUP",
     "VIRTUAL_ENV",
@@ -16,6 +25,7 @@ pub const PYTHON_ENVS_TO_REMOVE: [&str; 13] = [
     "PYTHONDEBUG",
     "PYTHONWARNINGS",
     "PYTHONIOENCODING",
+    "PYTHONUNBUFFERED",
     "PYTHONINSPECT",
 ];
 #[cfg(windows)]
@@ -31,38 +41,125 @@ fn system_default_path() -> String {
     .join(";")
 }
 
+fn is_env_kept(name: &str, keep_env: &[String]) -> bool {
+    keep_env.iter().any(|kept| kept.eq_ignore_ascii_case(name))
+}
+
+pub fn get_python_path_dirs(python_dir: &Path) -> Vec<PathBuf> {
+    if cfg!(windows) {
+        vec![python_dir.to_path_buf(), python_dir.join("Scripts")]
+    } else {
+        vec![python_dir.join("bin")]
+    }
+}
+
+fn get_command_path_value(command: &StdCommand) -> OsString {
+    match command
+        .get_envs()
+        .find(|(key, _)| key.eq_ignore_ascii_case("PATH"))
+    {
+        Some((_, value)) => value.map(OsStr::to_os_string).unwrap_or_default(),
+        None => env::var_os("PATH").unwrap_or_default(),
+    }
+}
+
+fn is_same_path_entry(a: &Path, b: &Path) -> bool {
+    if cfg!(windows) {
+        a.to_string_lossy()
+            .trim_end_matches(['\\', '/'])
+            .eq_ignore_ascii_case(b.to_string_lossy().trim_end_matches(['\\', '/']))
+    } else {
+        a == b
+    }
+}
+
+/// Prepends the python dirs that `existing_path` does not already contain.
+fn prepend_python_dirs_to_path(python_dir: &Path, existing_path: OsString) -> OsString {
+    let existing_dirs: Vec<PathBuf> = env::split_paths(&existing_path).collect();
+    let mut dirs: Vec<PathBuf> = get_python_path_dirs(python_dir)
+        .into_iter()
+        .filter(|dir| {
+            !existing_dirs
+                .iter()
+                .any(|existing| is_same_path_entry(existing, dir))
+        })
+        .collect();
+    if dirs.is_empty() {
+        return existing_path;
+    }
+    dirs.extend(existing_dirs);
+    env::join_paths(dirs).unwrap_or(existing_path)
+}
+
+/// Strips inherited Python-related variables before a child interpreter is spawned.
+///
+/// This is the first of two env passes for a launched app: it removes `PYTHON_ENVS_TO_REMOVE`
+/// plus the profile's `clear_env`, minus anything in `keep_env`. `PATH` is kept unless listed
+/// in `clear_env`, in which case it is reset to the system default on Windows. The variables
+/// returned by `build_python_execution_environment` are applied afterwards, so they always win
+/// over anything removed here.
 pub trait RemovePythonEnvsExt {
-    fn clear_python_envs(&mut self) -> &mut Self;
+    fn clear_python_envs(&mut self) -> &mut Self {
+        self.clear_python_envs_with(&[], &[])
+    }
+    fn clear_python_envs_with(&mut self, clear_env: &[String], keep_env: &[String]) -> &mut Self;
+    /// Prepends the managed interpreter and its `Scripts` dir to the command's `PATH`, skipping
+    /// any that are already on it.
+    fn prepend_python_path(&mut self, python_dir: &Path) -> &mut Self;
 }
 impl RemovePythonEnvsExt for StdCommand {
-    fn clear_python_envs(&mut self) -> &mut Self {
-        for env in PYTHON_ENVS_TO_REMOVE {
-            self.env_remove(env);
+    fn clear_python_envs_with(&mut self, clear_env: &[String], keep_env: &[String]) -> &mut Self {
+        for env in PYTHON_ENVS_TO_REMOVE
+            .iter()
+            .copied()
+            .chain(clear_env.iter().map(String::as_str))
+        {
+            if !is_env_kept(env, keep_env) {
+                self.env_remove(env);
+            }
         }
         #[cfg(windows)]
         {
-            self.env_remove("Path");
-            self.env_remove("PATH");
-            self.env("PATH", system_default_path());
+            if is_env_kept("PATH", clear_env) && !is_env_kept("PATH", keep_env) {
+                self.env_remove("Path");
+                self.env("PATH", system_default_path());
+            }
         }
         self.env("PYTHONNOUSERSITE", "1");
         self
     }
+
+    fn prepend_python_path(&mut self, python_dir: &Path) -> &mut Self {
+        let path = prepend_python_dirs_to_path(python_dir, get_command_path_value(self));
+        self.env("PATH", path)
+    }
 }
 impl RemovePythonEnvsExt for TokioCommand {
-    fn clear_python_envs(&mut self) -> &mut Self {
-        for env in PYTHON_ENVS_TO_REMOVE {
-            self.env_remove(env);
+    fn clear_python_envs_with(&mut self, clear_env: &[String], keep_env: &[String]) -> &mut Self {
+        for env in PYTHON_ENVS_TO_REMOVE
+            .iter()
+            .copied()
+            .chain(clear_env.iter().map(String::as_str))
+        {
+            if !is_env_kept(env, keep_env) {
+                self.env_remove(env);
+            }
         }
         #[cfg(windows)]
         {
-            self.env_remove("Path");
-            self.env_remove("PATH");
-            self.env("PATH", system_default_path());
+            if is_env_kept("PATH", clear_env) && !is_env_kept("PATH", keep_env) {
+                self.env_remove("Path");
+                self.env("PATH", system_default_path());
+            }
         }
         self.env("PYTHONNOUSERSITE", "1");
         self
     }
+
+    fn prepend_python_path(&mut self, python_dir: &Path) -> &mut Self {
+        let path = prepend_python_dirs_to_path(python_dir, get_command_path_value(self.as_std()));
+        self.env("PATH", path)
+    }
 }
 pub fn is_process_related_to_app_dir(process: &Process, app_dir_canonical: &Path) -> bool {
     if let Some(exe_path) = process.exe() {
@@ -73,6 +170,18 @@ pub fn is_process_related_to_app_dir(process: &Process, app_dir_canonical: &Path
     false
 }
 
+pub fn set_elevated_pid(app_name: &str, pid: Option<u32>) {
+    let mut elevated_pids = ELEVATED_PIDS.lock().unwrap();
+    match pid {
+        Some(pid) => elevated_pids.insert(app_name.to_string(), pid),
+        None => elevated_pids.remove(app_name),
+    };
+}
+
+pub fn get_elevated_pid(app_name: &str) -> Option<u32> {
+    ELEVATED_PIDS.lock().unwrap().get(app_name).copied()
+}
+
 pub fn get_pids_related_to_app_dir(sys: &System, app_dir_canonical: &PathBuf) -> Vec<Pid> {
     let mut related_pids = Vec::new();
     for (pid, process) in sys.processes() {
@@ -82,3 +191,68 @@ pub fn get_pids_related_to_app_dir(sys: &System, app_dir_canonical: &PathBuf) ->
     }
     related_pids
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        get_elevated_pid, get_python_path_dirs, prepend_python_dirs_to_path, set_elevated_pid,
+        RemovePythonEnvsExt,
+    };
+    use std::env;
+    use std::process::Command;
+
+    #[test]
+    fn tracks_the_elevated_launch_until_it_exits() {
+        assert_eq!(get_elevated_pid("elevated-demo"), None);
+        set_elevated_pid("elevated-demo", Some(4242));
+        assert_eq!(get_elevated_pid("elevated-demo"), Some(4242));
+        set_elevated_pid("elevated-demo", None);
+        assert_eq!(get_elevated_pid("elevated-demo"), None);
+    }
+
+    #[test]
+    fn prepends_python_dirs_only_once() {
+        let temp = tempfile::tempdir().unwrap();
+        let python_dir = temp.path().join("python");
+        let system_dir = temp.path().join("system");
+        let original = env::join_paths([&system_dir]).unwrap();
+
+        let once = prepend_python_dirs_to_path(&python_dir, original);
+        let twice = prepend_python_dirs_to_path(&python_dir, once.clone());
+        assert_eq!(twice, once);
+
+        let mut expected = get_python_path_dirs(&python_dir);
+        expected.push(system_dir);
+        assert_eq!(env::split_paths(&twice).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn child_path_keeps_system_path_and_includes_python_scripts() {
+        let temp = tempfile::tempdir().unwrap();
+        let python_dir = temp.path().join("python");
+        let mut command = if cfg!(windows) {
+            let mut command = Command::new("cmd");
+            command.args(["/C", "echo %PATH%"]);
+            command
+        } else {
+            let mut command = Command::new("sh");
+            command.args(["-c", "printf '%s' \"$PATH\""]);
+            command
+        };
+        command.clear_python_envs().prepend_python_path(&python_dir);
+
+        let output = command.output().unwrap();
+        let child_path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        let child_dirs: Vec<_> = env::split_paths(&child_path).collect();
+
+        assert!(!child_path.is_empty());
+        for python_path_dir in get_python_path_dirs(&python_dir) {
+            assert!(child_dirs.contains(&python_path_dir));
+        }
+        if let Some(parent_path) = env::var_os("PATH") {
+            for parent_dir in env::split_paths(&parent_path) {
+                assert!(child_dirs.contains(&parent_dir));
+            }
+        }
+    }
+}
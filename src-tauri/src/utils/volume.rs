@@ -0,0 +1,117 @@
+// src/utils/volume.rs
+use std::path::Path;
+
+/// A volume apps run badly from: slow or detachable. ReFS is not one of them, since Dev Drives
+/// use it and are faster than NTFS for the many small files of an app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlowVolume {
+    Network,
+    Removable,
+}
+
+impl SlowVolume {
+    pub fn describe(self) -> &'static str {
+        match self {
+            SlowVolume::Network => "a network drive",
+            SlowVolume::Removable => "a removable drive",
+        }
+    }
+}
+
+/// Classifies a file system by the name the platform reports for it.
+fn classify_file_system(name: &str) -> Option<SlowVolume> {
+    match name.to_ascii_lowercase().as_str() {
+        "nfs" | "nfs4" | "cifs" | "smbfs" | "smb3" | "9p" | "fuse.sshfs" | "davfs" => {
+            Some(SlowVolume::Network)
+        }
+        _ => None,
+    }
+}
+
+/// The slow volume `path` lives on, or None for a local fixed disk or when the volume can't be
+/// queried.
+#[cfg(windows)]
+pub fn detect_slow_volume(path: &Path) -> Option<SlowVolume> {
+    use std::ffi::OsStr;
+    use std::os::windows::ffi::OsStrExt;
+    use std::path::{Component, Prefix};
+    use std::ptr;
+    use windows_sys::Win32::Storage::FileSystem::{
+        GetDriveTypeW, GetVolumeInformationW, DRIVE_REMOTE, DRIVE_REMOVABLE,
+    };
+
+    let abs_path = std::path::absolute(path).ok()?;
+    let root = match abs_path.components().next()? {
+        Component::Prefix(prefix) => match prefix.kind() {
+            Prefix::UNC(..) | Prefix::VerbatimUNC(..) => return Some(SlowVolume::Network),
+            Prefix::Disk(letter) | Prefix::VerbatimDisk(letter) => {
+                format!("{}:\\", letter as char)
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let root = OsStr::new(&root)
+        .encode_wide()
+        .chain(Some(0))
+        .collect::<Vec<_>>();
+
+    match unsafe { GetDriveTypeW(root.as_ptr()) } {
+        DRIVE_REMOTE => return Some(SlowVolume::Network),
+        DRIVE_REMOVABLE => return Some(SlowVolume::Removable),
+        _ => {}
+    }
+    let mut file_system = [0u16; 32];
+    let found = unsafe {
+        GetVolumeInformationW(
+            root.as_ptr(),
+            ptr::null_mut(),
+            0,
+            ptr::null_mut(),
+            ptr::null_mut(),
+            ptr::null_mut(),
+            file_system.as_mut_ptr(),
+            file_system.len() as u32,
+        )
+    };
+    if found == 0 {
+        return None;
+    }
+    let len = file_system
+        .iter()
+        .position(|&c| c == 0)
+        .unwrap_or(file_system.len());
+    classify_file_system(&String::from_utf16_lossy(&file_system[..len]))
+}
+
+/// The slow volume `path` lives on, or None for a local fixed disk or when the volume can't be
+/// queried.
+#[cfg(not(windows))]
+pub fn detect_slow_volume(path: &Path) -> Option<SlowVolume> {
+    use sysinfo::Disks;
+
+    let abs_path = std::path::absolute(path).ok()?;
+    let disks = Disks::new_with_refreshed_list();
+    let disk = disks
+        .list()
+        .iter()
+        .filter(|disk| abs_path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())?;
+    if disk.is_removable() {
+        return Some(SlowVolume::Removable);
+    }
+    classify_file_system(&disk.file_system().to_string_lossy())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify_file_system, SlowVolume};
+
+    #[test]
+    fn classifies_only_network_file_systems_as_slow() {
+        assert_eq!(classify_file_system("ReFS"), None);
+        assert_eq!(classify_file_system("cifs"), Some(SlowVolume::Network));
+        assert_eq!(classify_file_system("NTFS"), None);
+        assert_eq!(classify_file_system("ext4"), None);
+    }
+}
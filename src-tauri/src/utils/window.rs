@@ -1,4 +1,6 @@
 // src/utils/window.rs
+use crate::app_service::stop_apps_for_exit;
+use crate::config_manager::get_stop_apps_on_exit;
 use crate::emitter::get_app_handle;
 use crate::utils::error::Error;
 use crate::utils::path::get_start_dir;
@@ -11,14 +13,32 @@ use tauri_plugin_notification::NotificationExt;
 use tracing::info;
 
 pub fn on_window_event(window: &Window, _event: &WindowEvent) {
-    if let WindowEvent::Resized(size) = _event {
-        if size.width == 0 && size.height == 0 && window.label() == "main" {
-            info!("on_window_event {:?}, hide", _event);
-            window.hide().unwrap();
+    match _event {
+        WindowEvent::Resized(size) => {
+            if size.width == 0 && size.height == 0 && window.label() == "main" {
+                info!("on_window_event {:?}, hide", _event);
+                window.hide().unwrap();
+            }
+        }
+        WindowEvent::CloseRequested { api, .. }
+            if window.label() == "main" && get_stop_apps_on_exit() =>
+        {
+            info!("main window close requested, stopping apps before exit");
+            api.prevent_close();
+            exit_after_stopping_apps(window.app_handle().clone());
         }
+        _ => {}
     }
 }
 
+/// Exits once `stop_apps_for_exit` is done, so quitting does not orphan running apps.
+fn exit_after_stopping_apps(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        stop_apps_for_exit().await;
+        app.exit(0);
+    });
+}
+
 pub fn send_notification(title: impl Into<String>, body: impl Into<String>) {
     let title = title.into();
     let body = body.into();
@@ -50,7 +70,7 @@ pub fn create_system_tray(app: &App<Wry>) -> anyhow::Result<()> {
         .on_menu_event(|app, event| match event.id.as_ref() {
             "quit" => {
                 println!("quit menu item was clicked");
-                app.exit(0);
+                exit_after_stopping_apps(app.clone());
             }
             _ => {
                 println!("menu item {:?} not handled", event.id);
@@ -49,25 +49,54 @@ import useMediaQuery from '@mui/material/useMediaQuery';
 import {useTranslation} from 'react-i18next';
 import {invokeTauriCommandWrapper} from "./utils.ts";
 
+interface SetupResult {
+    version: string | null;
+    python_version: string | null;
+    packages_installed: number;
+    duration_ms: number;
+    skipped_pip: boolean;
+}
+
+const describeSetupResult = (result: SetupResult) =>
+    `Done: version ${result.version ?? 'unknown'}, Python ${result.python_version ?? 'none'}, ` +
+    `${result.packages_installed} packages${result.skipped_pip ? ' (pip skipped)' : ''}, ` +
+    `took ${(result.duration_ms / 1000).toFixed(1)}s.`;
+
 interface Profile {
     name: string;
     main_script: string;
     admin: boolean;
-    requirements: string;
+    requirements: string[];
     python_path: string;
 }
 
+interface EffectiveProfile {
+    profile: Profile & { module: string; pip_args: string };
+    python_version: string | null;
+    pip_settings: { index_url: string | null; extra_index_url: string | null };
+}
+
 interface App {
     name: string;
     url: string;
     path: string;
     current_version: string | null;
+    head_description: string | null;
+    current_commit: string | null;
     available_versions: string[];
     running: boolean;
     installed: boolean;
+    install_state: 'not_installed' | 'repo_only' | 'env_missing' | 'installed';
+    pending_update: { from_version: string | null; from_revision: string | null; to_version: string } | null;
     profiles: Profile[];
     current_profile: string;
     show_add_defender: boolean;
+    operation_state: 'idle' | 'cloning' | 'downloading_python' | 'installing' | 'updating' | 'starting' | 'stopping';
+    last_run: { exit_code: number | null; crashed: boolean; stopped_by_user: boolean; finished_at: string } | null;
+    reported_status: { version: string | null; ready: boolean | null; message: string | null } | null;
+    current_version_removed: boolean;
+    python_version_warning: string | null;
+    nearest_surviving_version: string | null;
 }
 
 type ParsedVersion = {
@@ -193,6 +222,9 @@ function App() {
     const [appToDelete, setAppToDelete] = useState<string | null>(null);
     const [checkingUpdateForApp, setCheckingUpdateForApp] = useState<string | null>(null);
     const [appVersion, setAppVersion] = useState('');
+    const [safeMode, setSafeMode] = useState(false);
+    const [diskUsage, setDiskUsage] = useState<{ total: { formatted: string }; apps: unknown[] } | null>(null);
+    const [effectiveProfile, setEffectiveProfile] = useState<EffectiveProfile | null>(null);
     const [hiddenDefenderButtons, setHiddenDefenderButtons] = useState<Set<string>>(new Set());
     const [addingDefenderExclusionForApp, setAddingDefenderExclusionForApp] = useState<string | null>(null);
     const [snackbarOpen, setSnackbarOpen] = useState(false);
@@ -207,6 +239,15 @@ function App() {
         localStorage.setItem('appThemeMode', themeMode);
     }, [themeMode]);
 
+    useEffect(() => {
+        setEffectiveProfile(null);
+        if (currentPage !== 'changeProfile' || !appForProfileChange || !selectedNewProfileName) return;
+        invokeTauriCommandWrapper<EffectiveProfile>("get_effective_profile", {appName: appForProfileChange.name, profileName: selectedNewProfileName},
+            (result) => setEffectiveProfile(result),
+            (errorMessage) => console.error(`Failed to resolve profile ${selectedNewProfileName}:`, errorMessage)
+        );
+    }, [currentPage, appForProfileChange, selectedNewProfileName]);
+
     const prefersDarkMode = useMediaQuery('(prefers-color-scheme: dark)');
     const muiTheme = useMemo(() => {
         const mode: 'light' | 'dark' = themeMode === 'system' ? (prefersDarkMode ? 'dark' : 'light') : themeMode;
@@ -245,7 +286,8 @@ function App() {
         setIsInstallProcessRunning(true);
         setCurrentPage('installConsole');
 
-        await invokeTauriCommandWrapper<void>("setup_app", {appName, profileName}, () => {},
+        await invokeTauriCommandWrapper<SetupResult>("setup_app", {appName, profileName},
+            (result) => setConsoleInitialMessage(prev => `${prev}\n${describeSetupResult(result)}`),
             (errorMessage, rawError) => {
                 console.error(`Failed to invoke setup_app for ${appName} with profile ${profileName}:`, rawError);
                 setConsoleInitialMessage(prev => `${prev}\nERROR (client-side): Failed to dispatch install operation: ${errorMessage}`);
@@ -253,6 +295,38 @@ function App() {
         );
     };
 
+    const handleRepairApp = async (appName: string) => {
+        clearMessages();
+        setAppActionLoading(prev => ({...prev, [appName]: true}));
+        setStartingAppName(appName);
+        setConsoleInitialMessage(`Repairing the Python environment of '${appName}'...`);
+        setIsInstallProcessRunning(true);
+        setCurrentPage('installConsole');
+
+        await invokeTauriCommandWrapper<void>("repair_app", {appName}, () => {},
+            (errorMessage, rawError) => {
+                console.error(`Failed to invoke repair_app for ${appName}:`, rawError);
+                setConsoleInitialMessage(prev => `${prev}\nERROR (client-side): Failed to dispatch repair operation: ${errorMessage}`);
+            }
+        );
+    };
+
+    const handleRollbackPendingUpdate = async (appName: string) => {
+        clearMessages();
+        setAppActionLoading(prev => ({...prev, [appName]: true}));
+        setStartingAppName(appName);
+        setConsoleInitialMessage(`Rolling back the unfinished update of '${appName}'...`);
+        setIsInstallProcessRunning(true);
+        setCurrentPage('installConsole');
+
+        await invokeTauriCommandWrapper<void>("rollback_pending_update", {appName}, () => {},
+            (errorMessage, rawError) => {
+                console.error(`Failed to invoke rollback_pending_update for ${appName}:`, rawError);
+                setConsoleInitialMessage(prev => `${prev}\nERROR (client-side): Failed to dispatch rollback operation: ${errorMessage}`);
+            }
+        );
+    };
+
     const handleInstallClick = (app: App) => {
         if (app.profiles && app.profiles.length > 1) {
             setProfileChoiceApp(app);
@@ -267,8 +341,16 @@ function App() {
         }
     };
 
+    const installedAppCount = apps?.filter(app => app.installed).length ?? 0;
+    useEffect(() => {
+        invoke<{ total: { formatted: string }; apps: unknown[] }>('get_total_disk_usage')
+            .then(setDiskUsage)
+            .catch(error => console.error('Failed to get disk usage:', error));
+    }, [installedAppCount]);
+
     useEffect(() => {
         getVersion().then(setAppVersion);
+        invoke<boolean>('is_safe_mode').then(setSafeMode);
     }, []);
 
     useEffect(() => {
@@ -320,6 +402,17 @@ function App() {
             updateStatus({loading: false});
         }));
 
+        unlistenPromises.push(listen<{
+            app_name: string;
+            event: string;
+            payload: { message?: string; level?: string } | null;
+        }>("app-event", (event) => {
+            const {app_name, event: name, payload} = event.payload;
+            if (name === 'toast' && payload?.message) {
+                const message = `${app_name}: ${payload.message}`;
+                updateStatus(payload.level === 'error' ? {error: message} : {info: message});
+            }
+        }));
         unlistenPromises.push(listen<App>("choose_app_profile", (event) => {
             const app = event.payload;
             setProfileChoiceApp(app);
@@ -371,12 +464,12 @@ function App() {
         };
     }, [updateStatus]);
 
-    const handleDeleteApp = async (appName: string) => {
+    const handleDeleteApp = async (appName: string, keepData: boolean) => {
         clearMessages();
         updateStatus({messageLoading: true});
         setAppActionLoading(prev => ({...prev, [appName]: true}));
 
-        await invokeTauriCommandWrapper<void>("delete_app", {appName}, () => {},
+        await invokeTauriCommandWrapper<void>("uninstall_app", {appName, keepData}, () => {},
             (errorMessage, rawError) => {
                 console.error(`Failed to delete app ${appName}:`, rawError);
                 updateStatus({error: `Delete app ${appName} failed: ${errorMessage}`});
@@ -391,8 +484,8 @@ function App() {
         setConfirmDeleteDialogOpen(true);
     };
 
-    const handleConfirmDelete = () => {
-        if (appToDelete) handleDeleteApp(appToDelete);
+    const handleConfirmDelete = (keepData: boolean) => {
+        if (appToDelete) handleDeleteApp(appToDelete, keepData);
         setAppToDelete(null);
         setConfirmDeleteDialogOpen(false);
     };
@@ -452,7 +545,8 @@ function App() {
         const app = apps?.find(a => a.name === params.appName);
         const requirementsFile = app?.profiles?.find(p => p.name === app.current_profile)?.requirements || "requirements.txt";
 
-        await invokeTauriCommandWrapper<void>("update_to_version", {appName: params.appName, version: params.version, requirements: requirementsFile}, () => {},
+        await invokeTauriCommandWrapper<SetupResult>("update_to_version", {appName: params.appName, version: params.version, requirements: requirementsFile},
+            (result) => setConsoleInitialMessage(prev => `${prev}\n${describeSetupResult(result)}`),
             (errorMessage, rawError) => {
                 console.error(`Failed to invoke ${params.actionType.toLowerCase()}:`, rawError);
                 setConsoleInitialMessage(prev => `${prev}\nERROR (client-side): Failed to dispatch operation: ${errorMessage}`);
@@ -525,6 +619,24 @@ function App() {
 
     const handleConfirmProfileChange = async (appName: string, newProfileName: string) => {
         clearMessages();
+        let needsSetup = true;
+        await invokeTauriCommandWrapper<'switched' | 'needs_setup'>("set_active_profile", {appName, profile: newProfileName},
+            (result) => {
+                needsSetup = result === 'needs_setup';
+                if (!needsSetup) {
+                    updateStatus({info: t("Switched to profile '{{profile}}'.", {profile: newProfileName})});
+                }
+            },
+            (errorMessage, rawError) => {
+                console.error(`Failed to invoke set_active_profile for ${appName}:`, rawError);
+                updateStatus({error: errorMessage});
+                needsSetup = false;
+            }
+        );
+        if (!needsSetup) {
+            setCurrentPage('list');
+            return;
+        }
         setAppActionLoading(prev => ({...prev, [appName]: true}));
         setStartingAppName(appName);
         setProfileChangeData({appName, newProfile: newProfileName});
@@ -532,7 +644,8 @@ function App() {
         setIsProfileChangeProcessRunning(true);
         setCurrentPage('profileChangeConsole');
 
-        await invokeTauriCommandWrapper<void>("setup_app", {appName, profileName: newProfileName}, () => {},
+        await invokeTauriCommandWrapper<SetupResult>("setup_app", {appName, profileName: newProfileName},
+            (result) => setConsoleInitialMessage(prev => `${prev}\n${describeSetupResult(result)}`),
             (errorMessage, rawError) => {
                 console.error(`Failed to invoke setup_app for profile change:`, rawError);
                 setConsoleInitialMessage(prev => `${prev}\nERROR (client-side): Failed to dispatch operation: ${errorMessage}`);
@@ -557,7 +670,7 @@ function App() {
         clearMessages();
         setAppActionLoading(prev => ({...prev, [appName]: true}));
         setCheckingUpdateForApp(appName);
-        await invokeTauriCommandWrapper<void>("load_apps", undefined,
+        await invokeTauriCommandWrapper<App>("refresh_app", {appName},
             () => updateStatus({info: t("App Refreshed.")}),
             (errorMessage, rawError) => {
                 console.error("Failed to check for updates:", rawError);
@@ -641,6 +754,14 @@ function App() {
                                 {appForProfileChange.profiles.map(p => <MenuItem key={p.name} value={p.name} disabled={p.name === appForProfileChange.current_profile}>{p.name}{p.name === appForProfileChange.current_profile && t(" (Current)")}</MenuItem>)}
                             </Select>
                         </FormControl>
+                        {effectiveProfile && (
+                            <Box sx={{my: 2}}>
+                                <Typography variant="body2">{t('Python: {{version}}', {version: effectiveProfile.python_version ?? t('None')})}</Typography>
+                                <Typography variant="body2">{t('Requirements: {{requirements}}', {requirements: effectiveProfile.profile.requirements.join(', ') || t('None')})}</Typography>
+                                <Typography variant="body2">{t('Launches: {{target}}', {target: effectiveProfile.profile.module ? `-m ${effectiveProfile.profile.module}` : effectiveProfile.profile.main_script})}</Typography>
+                                <Typography variant="body2" sx={{wordBreak: 'break-all'}}>{t('Pip Index: {{url}}', {url: effectiveProfile.pip_settings.index_url ?? t('Default')})}</Typography>
+                            </Box>
+                        )}
                         <Stack direction="row" spacing={2} sx={{mt: 3, justifyContent: 'flex-end'}}>
                             <Button variant="outlined" onClick={() => setCurrentPage('list')}>{t('Cancel')}</Button>
                             <Button variant="contained" onClick={() => handleConfirmProfileChange(appForProfileChange.name, selectedNewProfileName)} disabled={!selectedNewProfileName || selectedNewProfileName === appForProfileChange.current_profile || appActionLoading[appForProfileChange.name]}>
@@ -655,8 +776,10 @@ function App() {
         pageContent = (
             <Container maxWidth="lg" sx={{py: 3}}>
                 <Box sx={{display: 'flex', justifyContent: 'flex-end', alignItems: 'center', mb: 2}}>
+                    {diskUsage && diskUsage.apps.length > 0 && <Typography variant="body2" color="text.secondary" sx={{mr: 1}}>{t('pyappify is using {{size}} across {{count}} apps', {size: diskUsage.total.formatted, count: diskUsage.apps.length})}</Typography>}
                     <IconButton onClick={() => setCurrentPage('settings')} color="inherit" title={t("Settings")}><SettingsIcon/></IconButton>
                 </Box>
+                {safeMode && <Alert severity="warning" sx={{mb: 2}}>{t('Started in safe mode: apps were not auto-updated or auto-started. Restart pyappify normally when done.')}</Alert>}
                 {status.messageLoading && <Box sx={{display: 'flex', alignItems: 'center', my: 2}}><CircularProgress size={24} sx={{mr: 1}}/><Typography>{t('Processing action...')}</Typography></Box>}
                 <Snackbar open={snackbarOpen} autoHideDuration={6000} onClose={() => setSnackbarOpen(false)} anchorOrigin={{vertical: 'bottom', horizontal: 'center'}}>
                     <Alert onClose={() => setSnackbarOpen(false)} severity={snackbarSeverity} sx={{width: '100%'}}>{snackbarMessage}</Alert>
@@ -667,22 +790,27 @@ function App() {
                     <List>
                         {apps.map((app) => {
                             const isEffectivelyInstalling = app.running && !app.installed;
+                            const needsRepair = app.install_state === 'env_missing' && !app.running;
                             const isThisAppLoading = appActionLoading[app.name] || false;
                             const disableRowActions = currentPage !== 'list' || status.messageLoading || isThisAppLoading;
                             return (
                                 <ListItem key={app.name} disablePadding sx={{mb: 2}}>
                                     <Card variant="outlined" sx={{width: '100%', bgcolor: (app.running) ? 'action.selected' : 'background.paper'}}>
                                         <CardContent>
-                                            <Typography variant="h6" component="div">
+                                            <Typography variant="h6" component="div" title={app.current_commit ?? undefined}>
                                                 {app.name}
-                                                {app.installed && app.current_version && ` (${app.current_version})`}
+                                                {app.installed && (app.head_description ?? app.current_version) && ` (${app.head_description ?? app.current_version})`}
                                                 {app.installed && app.current_profile && ` [${app.current_profile}]`}
-                                                {!app.installed && !isEffectivelyInstalling && <Typography component="span" color="text.secondary" sx={{ml: 1}}>{t('(Not Installed)')}</Typography>}
+                                                {needsRepair && <Typography component="span" color="warning.main" sx={{ml: 1}}>{t('(Environment Missing)')}</Typography>}
+                                                {!app.installed && !needsRepair && !isEffectivelyInstalling && <Typography component="span" color="text.secondary" sx={{ml: 1}}>{t('(Not Installed)')}</Typography>}
                                                 {isEffectivelyInstalling && <Typography component="span" color="info.main" sx={{ml: 1}}>{t('(Installing...)')}</Typography>}
                                                 {app.installed && app.running && <Typography component="span" color="success.main" sx={{ml: 1}}>{t('(Running)')}</Typography>}
+                                                {app.running && app.reported_status?.message && <Typography component="span" color="text.secondary" sx={{ml: 1}}>{app.reported_status.message}</Typography>}
                                             </Typography>
                                             <Stack direction={{xs: 'column', sm: 'row'}} spacing={1} sx={{my: 1, flexWrap: 'wrap', alignItems: 'center'}}>
-                                                {app.installed ? (
+                                                {needsRepair ? (
+                                                    <Button variant="contained" color="warning" size="small" startIcon={isThisAppLoading ? <CircularProgress size={16}/> : <Build/>} onClick={() => handleRepairApp(app.name)} disabled={disableRowActions}>{t("Repair")}</Button>
+                                                ) : app.installed ? (
                                                     app.running ? (
                                                         <>
                                                             <Button variant="outlined" color="warning" size="small" startIcon={isThisAppLoading ? <CircularProgress size={16}/> : <StopCircle/>} onClick={() => handleStopApp(app.name)} disabled={disableRowActions}>{t("Stop App")}</Button>
@@ -698,10 +826,23 @@ function App() {
                                                 )}
                                                 {app.show_add_defender && !hiddenDefenderButtons.has(app.name) && <Button variant="outlined" color="secondary" size="small" startIcon={isThisAppLoading && addingDefenderExclusionForApp === app.name ? <CircularProgress size={16}/> : <Build/>} onClick={() => handleAddDefenderExclusion(app.name)} disabled={disableRowActions}>{t("Add Defender Exclusion")}</Button>}
                                                 {app.installed && !app.running && app.profiles?.length > 1 && <Button variant="outlined" color="secondary" size="small" startIcon={isThisAppLoading ? <CircularProgress size={16}/> : <Cached/>} onClick={() => handleNavigateToChangeProfilePage(app)} disabled={disableRowActions}>{t("Change Profile")}</Button>}
+                                                {app.pending_update?.from_version && !app.running && <Button variant="outlined" color="warning" size="small" startIcon={isThisAppLoading ? <CircularProgress size={16}/> : <Cached/>} onClick={() => handleRollbackPendingUpdate(app.name)} disabled={disableRowActions}>{t("Roll Back to {{version}}", {version: app.pending_update.from_version})}</Button>}
                                                 {app.installed && <Button variant="outlined" color="error" size="small" startIcon={isThisAppLoading ? <CircularProgress size={16}/> : <Delete/>} onClick={() => handleDeleteClick(app.name)} disabled={disableRowActions || app.running}>{t("Delete")}</Button>}
                                             </Stack>
+                                            {app.pending_update && <Typography variant="body2" color="warning.main" sx={{mt: 1}}>{t('The update to {{version}} did not finish.', {version: app.pending_update.to_version})}</Typography>}
+                                            {app.python_version_warning && <Typography variant="body2" color="warning.main" sx={{mt: 1}}>{app.python_version_warning}</Typography>}
                                             {app.installed && !app.running && (
                                                 <Box sx={{mt: 2}}>
+                                                    {app.current_version_removed && (
+                                                        <Stack direction={{xs: 'column', sm: 'row'}} spacing={1} sx={{mb: 1, alignItems: 'center'}}>
+                                                            <Typography variant="body2" color="warning.main">{t("The installed version's tag was removed upstream.")}</Typography>
+                                                            {app.nearest_surviving_version && <Button variant="outlined" color="warning" size="small" disabled={disableRowActions} onClick={() => {
+                                                                const nearest = app.nearest_surviving_version!;
+                                                                setSelectedTargetVersions(p => ({...p, [app.name]: nearest}));
+                                                                handleVersionSelected(app.name, nearest, app.current_version);
+                                                            }}>{t('Move to {{version}}', {version: app.nearest_surviving_version})}</Button>}
+                                                        </Stack>
+                                                    )}
                                                     <Stack direction={{xs: 'column', sm: 'row'}} spacing={1} sx={{alignItems: 'center'}}>
                                                         {app.available_versions.filter(v => v !== app.current_version).length > 0 ? (
                                                             <>
@@ -761,7 +902,7 @@ function App() {
                 <Dialog open={isConfirmDeleteDialogOpen} onClose={handleCancelDelete}>
                     <DialogTitle>{t('Confirm Deletion')}</DialogTitle>
                     <DialogContent><DialogContentText>{appToDelete && t('Are you sure you want to delete {{appName}}?', {appName: appToDelete})}</DialogContentText></DialogContent>
-                    <DialogActions><Button onClick={handleCancelDelete}>{t('Cancel')}</Button><Button onClick={handleConfirmDelete} color="error" autoFocus>{t('Delete')}</Button></DialogActions>
+                    <DialogActions><Button onClick={handleCancelDelete}>{t('Cancel')}</Button><Button onClick={() => handleConfirmDelete(true)} color="warning">{t('Uninstall, Keep Data')}</Button><Button onClick={() => handleConfirmDelete(false)} color="error" autoFocus>{t('Delete')}</Button></DialogActions>
                 </Dialog>
             </Container>
         );
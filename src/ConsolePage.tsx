@@ -12,6 +12,7 @@ type MessagePayload = {
     update?: boolean;
     finished?: boolean;
     error?: boolean;
+    warning?: boolean;
 };
 
 interface ConsolePageProps {
@@ -183,7 +184,7 @@ const ConsolePage: React.FC<ConsolePageProps> = ({
                         key={index}
                         component="div"
                         sx={{
-                            color: logPayload.error ? 'error.main' : 'text.primary',
+                            color: logPayload.error ? 'error.main' : (logPayload.warning ? 'warning.main' : 'text.primary'),
                             mb: 0.5,
                             fontFamily: 'monospace',
                         }}
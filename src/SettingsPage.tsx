@@ -11,6 +11,7 @@ import {
     Paper,
     Select,
     SelectChangeEvent,
+    TextField,
     Typography
 } from '@mui/material';
 import i18n from "i18next";
@@ -37,10 +38,46 @@ interface ConfigItemFromRust {
     default_value: string | number;
     options?: (string | number)[];
 }
+interface EndpointCheck {
+    kind: string;
+    app_name: string | null;
+    url: string;
+    reachable: boolean;
+    latency_ms: number;
+    detail: string;
+}
+const ENDPOINT_KIND_LABELS: { [kind: string]: string } = {
+    pip_index: 'Pip Index',
+    python_download: 'Python Download',
+    git_remote: 'Git Remote',
+};
 const PIP_CACHE_DIR_CONFIG_KEY = "Pip Cache Directory";
 const PIP_INDEX_URL_CONFIG_KEY = "Pip Index URL";
 const LANGUAGE_CONFIG_KEY = "Language";
 const UPDATE_METHOD_CONFIG_KEY = "Update Method";
+const PYTHON_DOWNLOAD_MIRROR_CONFIG_KEY = "Python Download Mirror";
+const NETWORK_REGION_CONFIG_KEY = "Network Region";
+const NETWORK_REGION_OPTION_CUSTOM = "NETWORK_REGION_CUSTOM";
+const GIT_PROXY_CONFIG_KEY = "Git Proxy";
+const AUTO_START_CONFIG_KEY = "Auto Start";
+const STOP_APPS_ON_EXIT_CONFIG_KEY = "Stop Apps On Exit";
+const SLOW_DRIVE_WARNING_CONFIG_KEY = "Slow Drive Warning";
+const SECOND_LAUNCH_CONFIG_KEY = "Second Launch";
+const MISSING_PYTHON_CONFIG_KEY = "Missing Python";
+const NETWORK_RETRIES_CONFIG_KEY = "Network Retries";
+const MAX_CONCURRENT_INSTALLS_CONFIG_KEY = "Max Concurrent Installs";
+const CONFIG_BACKUPS_CONFIG_KEY = "Config Backups";
+const STATUS_UPDATE_INTERVAL_CONFIG_KEY = "Status Update Interval";
+const PIP_EXTRA_ARGS_CONFIG_KEY = "Pip Extra Args";
+const SOURCE_ADDRESS_CONFIG_KEY = "Source Address";
+const PROXY_CONFIG_KEY = "Proxy";
+const PIP_VERBOSITY_CONFIG_KEY = "Pip Verbosity";
+const APP_SORT_ORDER_CONFIG_KEY = "App Sort Order";
+const FETCH_ON_STARTUP_CONFIG_KEY = "Fetch On Startup";
+const SUPPRESSED_OUTPUT_CONFIG_KEY = "Suppressed Output";
+const PIP_INDEX_USERNAME_CONFIG_KEY = "Pip Index Username";
+const PIP_INDEX_PASSWORD_CONFIG_KEY = "Pip Index Password";
+const TEXT_CONFIG_KEYS = [PIP_EXTRA_ARGS_CONFIG_KEY, PIP_INDEX_USERNAME_CONFIG_KEY, PIP_INDEX_PASSWORD_CONFIG_KEY, SOURCE_ADDRESS_CONFIG_KEY, PROXY_CONFIG_KEY, SUPPRESSED_OUTPUT_CONFIG_KEY];
 
 const languageNames: { [key: string]: string } = { 'en': 'English', 'zh-CN': '简体中文', 'zh-TW': '繁體中文', 'es': 'Español', 'ja': '日本語', 'ko': '한국인' };
 
@@ -59,6 +96,9 @@ const SettingsPage: React.FC<SettingsPageProps> = ({ currentTheme, onChangeTheme
     const {t} = useTranslation();
     const [configs, setConfigs] = useState<ConfigItemFromRust[] | null>(null);
     const [isLoading, setIsLoading] = useState(true);
+    const [textValues, setTextValues] = useState<{ [key: string]: string }>({});
+    const [networkChecks, setNetworkChecks] = useState<EndpointCheck[] | null>(null);
+    const [isTestingNetwork, setIsTestingNetwork] = useState(false);
 
     const loadConfigs = async () => {
         setIsLoading(true);
@@ -66,6 +106,7 @@ const SettingsPage: React.FC<SettingsPageProps> = ({ currentTheme, onChangeTheme
             'get_config_payload', undefined,
             (result) => {
                 setConfigs(result);
+                setTextValues(Object.fromEntries(TEXT_CONFIG_KEYS.map(key => [key, (result.find(c => c.name === key)?.value as string) ?? ''])));
                 const languageConfig = result.find(c => c.name === LANGUAGE_CONFIG_KEY);
                 if (languageConfig && languageConfig.value) {
                     i18n.changeLanguage(languageConfig.value as string);
@@ -95,6 +136,17 @@ const SettingsPage: React.FC<SettingsPageProps> = ({ currentTheme, onChangeTheme
         );
     };
 
+    const handleDiagnoseNetwork = async () => {
+        setIsTestingNetwork(true);
+        setNetworkChecks(null);
+        await invokeTauriCommandWrapper<EndpointCheck[]>(
+            'test_network', {},
+            (checks) => setNetworkChecks(checks),
+            (errorMsg) => updateStatus({error: `Network test failed: ${errorMsg}`})
+        );
+        setIsTestingNetwork(false);
+    };
+
     if (isLoading || !configs) {
         return (
             <Container maxWidth="sm" sx={{py: 4, display: 'flex', justifyContent: 'center', alignItems: 'center', height: '100vh'}}>
@@ -107,8 +159,25 @@ const SettingsPage: React.FC<SettingsPageProps> = ({ currentTheme, onChangeTheme
     const languageConfig = getConfig(LANGUAGE_CONFIG_KEY);
     const themeConfig = { value: currentTheme, options: ['system', 'light', 'dark'] };
     const pipCacheConfig = getConfig(PIP_CACHE_DIR_CONFIG_KEY);
-    const pipIndexUrlConfig = getConfig(PIP_INDEX_URL_CONFIG_KEY);
+    const networkRegionConfig = getConfig(NETWORK_REGION_CONFIG_KEY);
+    // The region presets decide these, so they are only shown for a custom region.
+    const isCustomNetwork = !networkRegionConfig || networkRegionConfig.value === NETWORK_REGION_OPTION_CUSTOM;
+    const pipIndexUrlConfig = isCustomNetwork ? getConfig(PIP_INDEX_URL_CONFIG_KEY) : undefined;
     const updateMethodConfig = getConfig(UPDATE_METHOD_CONFIG_KEY);
+    const pythonDownloadMirrorConfig = isCustomNetwork ? getConfig(PYTHON_DOWNLOAD_MIRROR_CONFIG_KEY) : undefined;
+    const gitProxyConfig = isCustomNetwork ? getConfig(GIT_PROXY_CONFIG_KEY) : undefined;
+    const autoStartConfig = getConfig(AUTO_START_CONFIG_KEY);
+    const stopAppsOnExitConfig = getConfig(STOP_APPS_ON_EXIT_CONFIG_KEY);
+    const slowDriveWarningConfig = getConfig(SLOW_DRIVE_WARNING_CONFIG_KEY);
+    const secondLaunchConfig = getConfig(SECOND_LAUNCH_CONFIG_KEY);
+    const missingPythonConfig = getConfig(MISSING_PYTHON_CONFIG_KEY);
+    const networkRetriesConfig = getConfig(NETWORK_RETRIES_CONFIG_KEY);
+    const maxConcurrentInstallsConfig = getConfig(MAX_CONCURRENT_INSTALLS_CONFIG_KEY);
+    const configBackupsConfig = getConfig(CONFIG_BACKUPS_CONFIG_KEY);
+    const statusUpdateIntervalConfig = getConfig(STATUS_UPDATE_INTERVAL_CONFIG_KEY);
+    const pipVerbosityConfig = getConfig(PIP_VERBOSITY_CONFIG_KEY);
+    const fetchOnStartupConfig = getConfig(FETCH_ON_STARTUP_CONFIG_KEY);
+    const appSortOrderConfig = getConfig(APP_SORT_ORDER_CONFIG_KEY);
 
     return (
         <Container maxWidth="sm" sx={{py: 4}}>
@@ -118,18 +187,60 @@ const SettingsPage: React.FC<SettingsPageProps> = ({ currentTheme, onChangeTheme
                     { label: t('Language'), config: languageConfig, handler: (e: SelectChangeEvent) => handleSettingChange(LANGUAGE_CONFIG_KEY, e.target.value), renderOption: (o: string) => languageNames[o] || o },
                     { label: t('Theme'), config: themeConfig, handler: (e: SelectChangeEvent) => onChangeTheme(e.target.value as ThemeModeSetting), renderOption: (o: string) => t(o.charAt(0).toUpperCase() + o.slice(1)) },
                     { label: t('Pip Cache Directory'), config: pipCacheConfig, handler: (e: SelectChangeEvent) => handleSettingChange(PIP_CACHE_DIR_CONFIG_KEY, e.target.value), renderOption: (o: string) => t(o) },
+                    { label: t('Network Region'), config: networkRegionConfig, handler: (e: SelectChangeEvent) => handleSettingChange(NETWORK_REGION_CONFIG_KEY, e.target.value), renderOption: (o: string) => t(o) },
                     { label: t('Pip Index URL'), config: pipIndexUrlConfig, handler: (e: SelectChangeEvent) => handleSettingChange(PIP_INDEX_URL_CONFIG_KEY, e.target.value), renderOption: (o: string) => getPipIndexUrlName(o, t) },
                     { label: t('Update Method'), config: updateMethodConfig, handler: (e: SelectChangeEvent) => handleSettingChange(UPDATE_METHOD_CONFIG_KEY, e.target.value), renderOption: (o: string) => t(o) },
+                    { label: t('Auto Start'), config: autoStartConfig, handler: (e: SelectChangeEvent) => handleSettingChange(AUTO_START_CONFIG_KEY, e.target.value), renderOption: (o: string) => t(o) },
+                    { label: t('Stop Apps On Exit'), config: stopAppsOnExitConfig, handler: (e: SelectChangeEvent) => handleSettingChange(STOP_APPS_ON_EXIT_CONFIG_KEY, e.target.value), renderOption: (o: string) => t(o) },
+                    { label: t('Slow Drive Warning'), config: slowDriveWarningConfig, handler: (e: SelectChangeEvent) => handleSettingChange(SLOW_DRIVE_WARNING_CONFIG_KEY, e.target.value), renderOption: (o: string) => t(o) },
+                    { label: t('Second Launch'), config: secondLaunchConfig, handler: (e: SelectChangeEvent) => handleSettingChange(SECOND_LAUNCH_CONFIG_KEY, e.target.value), renderOption: (o: string) => t(o) },
+                    { label: t('Missing Python'), config: missingPythonConfig, handler: (e: SelectChangeEvent) => handleSettingChange(MISSING_PYTHON_CONFIG_KEY, e.target.value), renderOption: (o: string) => t(o) },
+                    { label: t('Python Download Mirror'), config: pythonDownloadMirrorConfig, handler: (e: SelectChangeEvent) => handleSettingChange(PYTHON_DOWNLOAD_MIRROR_CONFIG_KEY, e.target.value), renderOption: (o: string) => t(o) },
+                    { label: t('Git Proxy'), config: gitProxyConfig, handler: (e: SelectChangeEvent) => handleSettingChange(GIT_PROXY_CONFIG_KEY, e.target.value), renderOption: (o: string) => t(o) },
+                    { label: t('Network Retries'), config: networkRetriesConfig, handler: (e: SelectChangeEvent) => handleSettingChange(NETWORK_RETRIES_CONFIG_KEY, e.target.value), renderOption: (o: string) => String(o) },
+                    { label: t('Max Concurrent Installs'), config: maxConcurrentInstallsConfig, handler: (e: SelectChangeEvent) => handleSettingChange(MAX_CONCURRENT_INSTALLS_CONFIG_KEY, e.target.value), renderOption: (o: string) => String(o) },
+                    { label: t('Config Backups'), config: configBackupsConfig, handler: (e: SelectChangeEvent) => handleSettingChange(CONFIG_BACKUPS_CONFIG_KEY, e.target.value), renderOption: (o: string) => String(o) },
+                    { label: t('Status Update Interval'), config: statusUpdateIntervalConfig, handler: (e: SelectChangeEvent) => handleSettingChange(STATUS_UPDATE_INTERVAL_CONFIG_KEY, e.target.value), renderOption: (o: string) => t('{{count}}s', {count: Number(o)}) },
+                    { label: t('Pip Verbosity'), config: pipVerbosityConfig, handler: (e: SelectChangeEvent) => handleSettingChange(PIP_VERBOSITY_CONFIG_KEY, e.target.value), renderOption: (o: string) => t(o) },
+                    { label: t('App Sort Order'), config: appSortOrderConfig, handler: (e: SelectChangeEvent) => handleSettingChange(APP_SORT_ORDER_CONFIG_KEY, e.target.value), renderOption: (o: string) => t(o) },
+                    { label: t('Fetch On Startup'), config: fetchOnStartupConfig, handler: (e: SelectChangeEvent) => handleSettingChange(FETCH_ON_STARTUP_CONFIG_KEY, e.target.value), renderOption: (o: string) => t(o) },
                 ].map(({ label, config, handler, renderOption }) => config && (
                     <Box key={label} sx={{my: 2}}>
                         <FormControl fullWidth variant="outlined">
                             <InputLabel>{label}</InputLabel>
-                            <Select value={(config.value as string) || ''} label={label} onChange={handler}>
+                            <Select value={(config.value as string) ?? ''} label={label} onChange={handler}>
                                 {(config.options as string[])?.map(o => <MenuItem key={o} value={o}>{renderOption(o)}</MenuItem>)}
                             </Select>
                         </FormControl>
                     </Box>
                 ))}
+                {[
+                    { key: PIP_EXTRA_ARGS_CONFIG_KEY, helperText: t('Appended to every pip install after the profile pip_args') },
+                    { key: PIP_INDEX_USERNAME_CONFIG_KEY, helperText: t('Credentials for a private pip index, passed to pip through the environment') },
+                    { key: PIP_INDEX_PASSWORD_CONFIG_KEY, helperText: t('Stored unencrypted in the config file'), type: 'password' },
+                    { key: SOURCE_ADDRESS_CONFIG_KEY, helperText: t('Local IP address for Python downloads, empty for the OS default') },
+                    { key: PROXY_CONFIG_KEY, helperText: t('Used by pip, Python downloads and git; git only uses http(s) proxies') },
+                    { key: SUPPRESSED_OUTPUT_CONFIG_KEY, helperText: t('Separated by ;, matching error output is only written to the log file') },
+                ].map(({ key, helperText, type }) => {
+                    const config = getConfig(key);
+                    const value = textValues[key] ?? '';
+                    return config && (
+                        <Box key={key} sx={{my: 2}}>
+                            <TextField fullWidth variant="outlined" label={t(key)} value={value} type={type} helperText={helperText}
+                                       onChange={(e) => setTextValues(prev => ({...prev, [key]: e.target.value}))}
+                                       onBlur={() => value !== config.value && handleSettingChange(key, key === SOURCE_ADDRESS_CONFIG_KEY || key === PROXY_CONFIG_KEY ? value.trim() : value)}/>
+                        </Box>
+                    );
+                })}
+                <Box sx={{my: 2}}>
+                    <Button variant="outlined" onClick={handleDiagnoseNetwork} disabled={isTestingNetwork}
+                            startIcon={isTestingNetwork ? <CircularProgress size={16}/> : undefined}>{t('Diagnose Network')}</Button>
+                    {networkChecks?.map(check => (
+                        <Typography key={`${check.kind}-${check.app_name ?? ''}`} variant="body2" color={check.reachable ? 'success.main' : 'error.main'} sx={{mt: 1, wordBreak: 'break-all'}}>
+                            {t(ENDPOINT_KIND_LABELS[check.kind] ?? check.kind)}{check.app_name ? ` (${check.app_name})` : ''}: {check.reachable ? t('Reachable in {{ms}} ms', {ms: check.latency_ms}) : t('Unreachable')} — {check.url} {check.detail}
+                        </Typography>
+                    ))}
+                </Box>
                 <Box sx={{mt: 4, display: 'flex', justifyContent: 'center'}}>
                     <Button variant="outlined" onClick={onBack}>{t('Back to App')}</Button>
                 </Box>
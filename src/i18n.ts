@@ -108,7 +108,88 @@ const resources = {
             "Update Method": "Update Method",
             "MANUAL_UPDATE": "Manual Update",
             "AUTO_UPDATE": "Auto Update",
-            "IGNORE_UPDATE": "Ignore Updates"
+            "IGNORE_UPDATE": "Ignore Updates",
+            "Python Download Mirror": "Python Download Mirror",
+            "PYTHON_MIRROR_AUTO": "Auto (by region)",
+            "PYTHON_MIRROR_OFFICIAL": "Official (python.org, GitHub)",
+            "PYTHON_MIRROR_CHINA": "China mirror (Huawei Cloud, ModelScope)",
+            "Auto Start": "Auto Start",
+            "AUTO_START_OFF": "Off",
+            "AUTO_START_IF_INSTALLED": "If Installed",
+            "AUTO_START_IF_LATEST": "If Latest Version",
+            "Network Retries": "Network Retries",
+            "Pip Extra Args": "Pip Extra Args",
+            "Appended to every pip install after the profile pip_args": "Appended to every pip install after the profile pip_args",
+            "Source Address": "Source Address",
+            "Local IP address for Python downloads, empty for the OS default": "Local IP address for Python downloads, empty for the OS default",
+            "Pip Index Username": "Pip Index Username",
+            "Pip Index Password": "Pip Index Password",
+            "Credentials for a private pip index, passed to pip through the environment": "Credentials for a private pip index, passed to pip through the environment",
+            "Stored unencrypted in the config file": "Stored unencrypted in the config file",
+            "Pip Verbosity": "Pip Verbosity",
+            "PIP_VERBOSITY_QUIET": "Quiet",
+            "PIP_VERBOSITY_NORMAL": "Normal",
+            "PIP_VERBOSITY_VERBOSE": "Verbose",
+            "PIP_VERBOSITY_VERY_VERBOSE": "Very Verbose",
+            "Suppressed Output": "Suppressed Output",
+            "Separated by ;, matching error output is only written to the log file": "Separated by ;, matching error output is only written to the log file",
+            "The installed version's tag was removed upstream.": "The installed version's tag was removed upstream.",
+            "Move to {{version}}": "Move to {{version}}",
+            "Fetch On Startup": "Fetch On Startup",
+            "FETCH_ON_STARTUP_ALWAYS": "Always",
+            "FETCH_ON_STARTUP_INTERVAL": "Once a day",
+            "FETCH_ON_STARTUP_NEVER": "Never",
+            "Max Concurrent Installs": "Max Concurrent Installs",
+            "Status Update Interval": "Status Update Interval",
+            "{{count}}s": "{{count}}s",
+            "Stop Apps On Exit": "Stop Apps On Exit",
+            "STOP_APPS_ON_EXIT_OFF": "Off (keep apps running)",
+            "STOP_APPS_ON_EXIT_ON": "On",
+            "Missing Python": "When Python Is Missing",
+            "MISSING_PYTHON_REPAIR": "Reinstall Python only",
+            "MISSING_PYTHON_RESET": "Delete the whole app",
+            "Network Region": "Network Region",
+            "NETWORK_REGION_CHINA": "China (Aliyun pip, China Python mirror)",
+            "NETWORK_REGION_GLOBAL": "Global (PyPI, official Python builds)",
+            "NETWORK_REGION_CUSTOM": "Custom",
+            "Git Proxy": "Git Proxy",
+            "GIT_PROXY_AUTO": "Auto-detect",
+            "GIT_PROXY_OFF": "Off",
+            "Config Backups": "Config Backups",
+            "Switched to profile '{{profile}}'.": "Switched to profile '{{profile}}'.",
+            "Slow Drive Warning": "Slow Drive Warning",
+            "SLOW_DRIVE_WARNING_ON": "On",
+            "SLOW_DRIVE_WARNING_OFF": "Off",
+            "App Sort Order": "App Sort Order",
+            "APP_SORT_ORDER_RUNNING_FIRST": "Running first",
+            "APP_SORT_ORDER_ALPHABETICAL": "Alphabetical",
+            "APP_SORT_ORDER_LAST_START": "Recently started",
+            "APP_SORT_ORDER_INSTALL_DATE": "Install date",
+            "(Environment Missing)": "(Environment Missing)",
+            "Repair": "Repair",
+            "Uninstall, Keep Data": "Uninstall, Keep Data",
+            "Started in safe mode: apps were not auto-updated or auto-started. Restart pyappify normally when done.": "Started in safe mode: apps were not auto-updated or auto-started. Restart pyappify normally when done.",
+            "Second Launch": "Second Launch",
+            "SECOND_LAUNCH_FOCUS_WINDOW": "Show Window",
+            "SECOND_LAUNCH_FORWARD_COMMAND": "Run Command",
+            "SECOND_LAUNCH_SILENT": "Silent",
+            "pyappify is using {{size}} across {{count}} apps": "pyappify is using {{size}} across {{count}} apps",
+            "Diagnose Network": "Diagnose Network",
+            "Pip Index": "Pip Index",
+            "Python Download": "Python Download",
+            "Git Remote": "Git Remote",
+            "Reachable in {{ms}} ms": "Reachable in {{ms}} ms",
+            "Unreachable": "Unreachable",
+            "Roll Back to {{version}}": "Roll Back to {{version}}",
+            "The update to {{version}} did not finish.": "The update to {{version}} did not finish.",
+            "Python: {{version}}": "Python: {{version}}",
+            "Requirements: {{requirements}}": "Requirements: {{requirements}}",
+            "Launches: {{target}}": "Launches: {{target}}",
+            "Pip Index: {{url}}": "Pip Index: {{url}}",
+            "None": "None",
+            "Default": "Default",
+            "Proxy": "Proxy",
+            "Used by pip, Python downloads and git; git only uses http(s) proxies": "Used by pip, Python downloads and git; git only uses http(s) proxies"
         }
     },
     'zh-CN': {
@@ -217,7 +298,88 @@ const resources = {
             "Update Method": "更新方式",
             "MANUAL_UPDATE": "手动更新",
             "AUTO_UPDATE": "自动更新",
-            "IGNORE_UPDATE": "忽略更新"
+            "IGNORE_UPDATE": "忽略更新",
+            "Python Download Mirror": "Python 下载源",
+            "PYTHON_MIRROR_AUTO": "自动（按区域）",
+            "PYTHON_MIRROR_OFFICIAL": "官方（python.org、GitHub）",
+            "PYTHON_MIRROR_CHINA": "国内镜像（华为云、ModelScope）",
+            "Auto Start": "自动启动",
+            "AUTO_START_OFF": "关闭",
+            "AUTO_START_IF_INSTALLED": "已安装时",
+            "AUTO_START_IF_LATEST": "最新版本时",
+            "Network Retries": "网络重试次数",
+            "Pip Extra Args": "Pip 额外参数",
+            "Appended to every pip install after the profile pip_args": "在配置文件的 pip_args 之后追加到每次 pip install",
+            "Source Address": "源地址",
+            "Local IP address for Python downloads, empty for the OS default": "Python 下载使用的本地 IP 地址，留空使用系统默认",
+            "Pip Index Username": "Pip 索引用户名",
+            "Pip Index Password": "Pip 索引密码",
+            "Credentials for a private pip index, passed to pip through the environment": "私有 pip 索引的凭据，通过环境变量传给 pip",
+            "Stored unencrypted in the config file": "以明文保存在配置文件中",
+            "Pip Verbosity": "Pip 输出详细程度",
+            "PIP_VERBOSITY_QUIET": "安静",
+            "PIP_VERBOSITY_NORMAL": "正常",
+            "PIP_VERBOSITY_VERBOSE": "详细",
+            "PIP_VERBOSITY_VERY_VERBOSE": "非常详细",
+            "Suppressed Output": "屏蔽的输出",
+            "Separated by ;, matching error output is only written to the log file": "以 ; 分隔，匹配的错误输出只写入日志文件",
+            "The installed version's tag was removed upstream.": "已安装版本的标签已在上游被删除。",
+            "Move to {{version}}": "切换到 {{version}}",
+            "Fetch On Startup": "启动时获取更新",
+            "FETCH_ON_STARTUP_ALWAYS": "总是",
+            "FETCH_ON_STARTUP_INTERVAL": "每天一次",
+            "FETCH_ON_STARTUP_NEVER": "从不",
+            "Max Concurrent Installs": "最大并行安装数",
+            "Status Update Interval": "状态刷新间隔",
+            "{{count}}s": "{{count}} 秒",
+            "Stop Apps On Exit": "退出时停止应用",
+            "STOP_APPS_ON_EXIT_OFF": "关闭（保持应用运行）",
+            "STOP_APPS_ON_EXIT_ON": "开启",
+            "Missing Python": "Python 缺失时",
+            "MISSING_PYTHON_REPAIR": "仅重新安装 Python",
+            "MISSING_PYTHON_RESET": "删除整个应用",
+            "Network Region": "网络区域",
+            "NETWORK_REGION_CHINA": "中国（阿里云 pip、国内 Python 镜像）",
+            "NETWORK_REGION_GLOBAL": "全球（PyPI、官方 Python）",
+            "NETWORK_REGION_CUSTOM": "自定义",
+            "Git Proxy": "Git 代理",
+            "GIT_PROXY_AUTO": "自动检测",
+            "GIT_PROXY_OFF": "关闭",
+            "Config Backups": "配置备份数量",
+            "Switched to profile '{{profile}}'.": "已切换到配置 '{{profile}}'。",
+            "Slow Drive Warning": "慢速驱动器警告",
+            "SLOW_DRIVE_WARNING_ON": "开启",
+            "SLOW_DRIVE_WARNING_OFF": "关闭",
+            "App Sort Order": "应用排序",
+            "APP_SORT_ORDER_RUNNING_FIRST": "运行中优先",
+            "APP_SORT_ORDER_ALPHABETICAL": "按名称",
+            "APP_SORT_ORDER_LAST_START": "最近启动",
+            "APP_SORT_ORDER_INSTALL_DATE": "安装日期",
+            "(Environment Missing)": "(环境缺失)",
+            "Repair": "修复",
+            "Uninstall, Keep Data": "卸载但保留数据",
+            "Started in safe mode: apps were not auto-updated or auto-started. Restart pyappify normally when done.": "已以安全模式启动：应用未自动更新或自动启动。处理完成后请正常重启 pyappify。",
+            "Second Launch": "重复启动",
+            "SECOND_LAUNCH_FOCUS_WINDOW": "显示窗口",
+            "SECOND_LAUNCH_FORWARD_COMMAND": "执行命令",
+            "SECOND_LAUNCH_SILENT": "静默",
+            "pyappify is using {{size}} across {{count}} apps": "pyappify 的 {{count}} 个应用共占用 {{size}}",
+            "Diagnose Network": "网络诊断",
+            "Pip Index": "Pip 镜像源",
+            "Python Download": "Python 下载",
+            "Git Remote": "Git 远程仓库",
+            "Reachable in {{ms}} ms": "可访问，耗时 {{ms}} 毫秒",
+            "Unreachable": "无法访问",
+            "Roll Back to {{version}}": "回滚到 {{version}}",
+            "The update to {{version}} did not finish.": "更新到 {{version}} 未完成。",
+            "Python: {{version}}": "Python：{{version}}",
+            "Requirements: {{requirements}}": "依赖：{{requirements}}",
+            "Launches: {{target}}": "启动：{{target}}",
+            "Pip Index: {{url}}": "Pip 镜像源：{{url}}",
+            "None": "无",
+            "Default": "默认",
+            "Proxy": "代理",
+            "Used by pip, Python downloads and git; git only uses http(s) proxies": "用于 pip、Python 下载和 git；git 仅使用 http(s) 代理"
         }
     },
     'zh-TW': {
@@ -326,7 +488,88 @@ const resources = {
             "Update Method": "更新方式",
             "MANUAL_UPDATE": "手動更新",
             "AUTO_UPDATE": "自動更新",
-            "IGNORE_UPDATE": "忽略更新"
+            "IGNORE_UPDATE": "忽略更新",
+            "Python Download Mirror": "Python 下載來源",
+            "PYTHON_MIRROR_AUTO": "自動（依區域）",
+            "PYTHON_MIRROR_OFFICIAL": "官方（python.org、GitHub）",
+            "PYTHON_MIRROR_CHINA": "中國鏡像（華為雲、ModelScope）",
+            "Auto Start": "自動啟動",
+            "AUTO_START_OFF": "關閉",
+            "AUTO_START_IF_INSTALLED": "已安裝時",
+            "AUTO_START_IF_LATEST": "最新版本時",
+            "Network Retries": "網路重試次數",
+            "Pip Extra Args": "Pip 額外參數",
+            "Appended to every pip install after the profile pip_args": "在設定檔的 pip_args 之後附加到每次 pip install",
+            "Source Address": "來源位址",
+            "Local IP address for Python downloads, empty for the OS default": "Python 下載使用的本機 IP 位址，留空使用系統預設",
+            "Pip Index Username": "Pip 索引使用者名稱",
+            "Pip Index Password": "Pip 索引密碼",
+            "Credentials for a private pip index, passed to pip through the environment": "私有 pip 索引的憑證，透過環境變數傳給 pip",
+            "Stored unencrypted in the config file": "以明文儲存在設定檔中",
+            "Pip Verbosity": "Pip 輸出詳細程度",
+            "PIP_VERBOSITY_QUIET": "安靜",
+            "PIP_VERBOSITY_NORMAL": "正常",
+            "PIP_VERBOSITY_VERBOSE": "詳細",
+            "PIP_VERBOSITY_VERY_VERBOSE": "非常詳細",
+            "Suppressed Output": "遮蔽的輸出",
+            "Separated by ;, matching error output is only written to the log file": "以 ; 分隔，符合的錯誤輸出只寫入日誌檔",
+            "The installed version's tag was removed upstream.": "已安裝版本的標籤已在上游被刪除。",
+            "Move to {{version}}": "切換到 {{version}}",
+            "Fetch On Startup": "啟動時取得更新",
+            "FETCH_ON_STARTUP_ALWAYS": "總是",
+            "FETCH_ON_STARTUP_INTERVAL": "每天一次",
+            "FETCH_ON_STARTUP_NEVER": "從不",
+            "Max Concurrent Installs": "最大並行安裝數",
+            "Status Update Interval": "狀態更新間隔",
+            "{{count}}s": "{{count}} 秒",
+            "Stop Apps On Exit": "結束時停止應用",
+            "STOP_APPS_ON_EXIT_OFF": "關閉（保持應用執行）",
+            "STOP_APPS_ON_EXIT_ON": "開啟",
+            "Missing Python": "Python 遺失時",
+            "MISSING_PYTHON_REPAIR": "僅重新安裝 Python",
+            "MISSING_PYTHON_RESET": "刪除整個應用",
+            "Network Region": "網路區域",
+            "NETWORK_REGION_CHINA": "中國（阿里雲 pip、中國 Python 鏡像）",
+            "NETWORK_REGION_GLOBAL": "全球（PyPI、官方 Python）",
+            "NETWORK_REGION_CUSTOM": "自訂",
+            "Git Proxy": "Git 代理",
+            "GIT_PROXY_AUTO": "自動偵測",
+            "GIT_PROXY_OFF": "關閉",
+            "Config Backups": "設定備份數量",
+            "Switched to profile '{{profile}}'.": "已切換到設定檔 '{{profile}}'。",
+            "Slow Drive Warning": "慢速磁碟警告",
+            "SLOW_DRIVE_WARNING_ON": "開啟",
+            "SLOW_DRIVE_WARNING_OFF": "關閉",
+            "App Sort Order": "應用程式排序",
+            "APP_SORT_ORDER_RUNNING_FIRST": "執行中優先",
+            "APP_SORT_ORDER_ALPHABETICAL": "依名稱",
+            "APP_SORT_ORDER_LAST_START": "最近啟動",
+            "APP_SORT_ORDER_INSTALL_DATE": "安裝日期",
+            "(Environment Missing)": "(環境缺失)",
+            "Repair": "修復",
+            "Uninstall, Keep Data": "解除安裝但保留資料",
+            "Started in safe mode: apps were not auto-updated or auto-started. Restart pyappify normally when done.": "已以安全模式啟動：應用程式未自動更新或自動啟動。處理完成後請正常重新啟動 pyappify。",
+            "Second Launch": "重複啟動",
+            "SECOND_LAUNCH_FOCUS_WINDOW": "顯示視窗",
+            "SECOND_LAUNCH_FORWARD_COMMAND": "執行命令",
+            "SECOND_LAUNCH_SILENT": "靜默",
+            "pyappify is using {{size}} across {{count}} apps": "pyappify 的 {{count}} 個應用程式共佔用 {{size}}",
+            "Diagnose Network": "網路診斷",
+            "Pip Index": "Pip 索引",
+            "Python Download": "Python 下載",
+            "Git Remote": "Git 遠端儲存庫",
+            "Reachable in {{ms}} ms": "可連線，耗時 {{ms}} 毫秒",
+            "Unreachable": "無法連線",
+            "Roll Back to {{version}}": "回復到 {{version}}",
+            "The update to {{version}} did not finish.": "更新到 {{version}} 未完成。",
+            "Python: {{version}}": "Python：{{version}}",
+            "Requirements: {{requirements}}": "相依套件：{{requirements}}",
+            "Launches: {{target}}": "啟動：{{target}}",
+            "Pip Index: {{url}}": "Pip 索引：{{url}}",
+            "None": "無",
+            "Default": "預設",
+            "Proxy": "代理",
+            "Used by pip, Python downloads and git; git only uses http(s) proxies": "用於 pip、Python 下載和 git；git 僅使用 http(s) 代理"
         }
     },
     ja: {
@@ -435,7 +678,88 @@ const resources = {
             "Update Method": "更新方法",
             "MANUAL_UPDATE": "手動更新",
             "AUTO_UPDATE": "自動更新",
-            "IGNORE_UPDATE": "更新を無視"
+            "IGNORE_UPDATE": "更新を無視",
+            "Python Download Mirror": "Python ダウンロードミラー",
+            "PYTHON_MIRROR_AUTO": "自動（地域別）",
+            "PYTHON_MIRROR_OFFICIAL": "公式（python.org、GitHub）",
+            "PYTHON_MIRROR_CHINA": "中国ミラー（Huawei Cloud、ModelScope）",
+            "Auto Start": "自動起動",
+            "AUTO_START_OFF": "オフ",
+            "AUTO_START_IF_INSTALLED": "インストール済みの場合",
+            "AUTO_START_IF_LATEST": "最新バージョンの場合",
+            "Network Retries": "ネットワーク再試行回数",
+            "Pip Extra Args": "Pip 追加引数",
+            "Appended to every pip install after the profile pip_args": "プロファイルの pip_args の後にすべての pip install に追加されます",
+            "Source Address": "送信元アドレス",
+            "Local IP address for Python downloads, empty for the OS default": "Python のダウンロードに使うローカル IP アドレス。空欄で OS の既定",
+            "Pip Index Username": "Pip インデックスのユーザー名",
+            "Pip Index Password": "Pip インデックスのパスワード",
+            "Credentials for a private pip index, passed to pip through the environment": "プライベート pip インデックスの認証情報。環境変数で pip に渡されます",
+            "Stored unencrypted in the config file": "設定ファイルに暗号化されずに保存されます",
+            "Pip Verbosity": "Pip の出力レベル",
+            "PIP_VERBOSITY_QUIET": "最小限",
+            "PIP_VERBOSITY_NORMAL": "標準",
+            "PIP_VERBOSITY_VERBOSE": "詳細",
+            "PIP_VERBOSITY_VERY_VERBOSE": "非常に詳細",
+            "Suppressed Output": "非表示にする出力",
+            "Separated by ;, matching error output is only written to the log file": "; 区切り。一致するエラー出力はログファイルにのみ書き込まれます",
+            "The installed version's tag was removed upstream.": "インストール済みバージョンのタグはアップストリームで削除されました。",
+            "Move to {{version}}": "{{version}} に移行",
+            "Fetch On Startup": "起動時に取得",
+            "FETCH_ON_STARTUP_ALWAYS": "常に",
+            "FETCH_ON_STARTUP_INTERVAL": "1日1回",
+            "FETCH_ON_STARTUP_NEVER": "しない",
+            "Max Concurrent Installs": "同時インストール数の上限",
+            "Status Update Interval": "ステータス更新間隔",
+            "{{count}}s": "{{count}} 秒",
+            "Stop Apps On Exit": "終了時にアプリを停止",
+            "STOP_APPS_ON_EXIT_OFF": "オフ（アプリを実行したまま）",
+            "STOP_APPS_ON_EXIT_ON": "オン",
+            "Missing Python": "Python が見つからない場合",
+            "MISSING_PYTHON_REPAIR": "Python のみ再インストール",
+            "MISSING_PYTHON_RESET": "アプリ全体を削除",
+            "Network Region": "ネットワーク地域",
+            "NETWORK_REGION_CHINA": "中国（Aliyun pip、中国 Python ミラー）",
+            "NETWORK_REGION_GLOBAL": "グローバル（PyPI、公式 Python）",
+            "NETWORK_REGION_CUSTOM": "カスタム",
+            "Git Proxy": "Git プロキシ",
+            "GIT_PROXY_AUTO": "自動検出",
+            "GIT_PROXY_OFF": "オフ",
+            "Config Backups": "設定のバックアップ数",
+            "Switched to profile '{{profile}}'.": "プロファイル '{{profile}}' に切り替えました。",
+            "Slow Drive Warning": "低速ドライブの警告",
+            "SLOW_DRIVE_WARNING_ON": "オン",
+            "SLOW_DRIVE_WARNING_OFF": "オフ",
+            "App Sort Order": "アプリの並び順",
+            "APP_SORT_ORDER_RUNNING_FIRST": "実行中を先頭",
+            "APP_SORT_ORDER_ALPHABETICAL": "名前順",
+            "APP_SORT_ORDER_LAST_START": "最近起動した順",
+            "APP_SORT_ORDER_INSTALL_DATE": "インストール日",
+            "(Environment Missing)": "(環境がありません)",
+            "Repair": "修復",
+            "Uninstall, Keep Data": "データを残してアンインストール",
+            "Started in safe mode: apps were not auto-updated or auto-started. Restart pyappify normally when done.": "セーフモードで起動しました：アプリの自動更新と自動起動は行われていません。作業が終わったら pyappify を通常どおり再起動してください。",
+            "Second Launch": "二重起動時",
+            "SECOND_LAUNCH_FOCUS_WINDOW": "ウィンドウを表示",
+            "SECOND_LAUNCH_FORWARD_COMMAND": "コマンドを実行",
+            "SECOND_LAUNCH_SILENT": "サイレント",
+            "pyappify is using {{size}} across {{count}} apps": "pyappify は {{count}} 個のアプリで {{size}} を使用しています",
+            "Diagnose Network": "ネットワーク診断",
+            "Pip Index": "Pipインデックス",
+            "Python Download": "Pythonダウンロード",
+            "Git Remote": "Gitリモート",
+            "Reachable in {{ms}} ms": "{{ms}} ミリ秒で到達",
+            "Unreachable": "到達不可",
+            "Roll Back to {{version}}": "{{version}} にロールバック",
+            "The update to {{version}} did not finish.": "{{version}} への更新が完了していません。",
+            "Python: {{version}}": "Python: {{version}}",
+            "Requirements: {{requirements}}": "依存関係: {{requirements}}",
+            "Launches: {{target}}": "起動対象: {{target}}",
+            "Pip Index: {{url}}": "Pipインデックス: {{url}}",
+            "None": "なし",
+            "Default": "デフォルト",
+            "Proxy": "プロキシ",
+            "Used by pip, Python downloads and git; git only uses http(s) proxies": "pip、Python のダウンロード、git で使用。git は http(s) プロキシのみ使用"
         }
     },
     ko: {
@@ -544,7 +868,88 @@ const resources = {
             "Update Method": "업데이트 방법",
             "MANUAL_UPDATE": "수동 업데이트",
             "AUTO_UPDATE": "자동 업데이트",
-            "IGNORE_UPDATE": "업데이트 무시"
+            "IGNORE_UPDATE": "업데이트 무시",
+            "Python Download Mirror": "Python 다운로드 미러",
+            "PYTHON_MIRROR_AUTO": "자동 (지역 기준)",
+            "PYTHON_MIRROR_OFFICIAL": "공식 (python.org, GitHub)",
+            "PYTHON_MIRROR_CHINA": "중국 미러 (Huawei Cloud, ModelScope)",
+            "Auto Start": "자동 시작",
+            "AUTO_START_OFF": "끄기",
+            "AUTO_START_IF_INSTALLED": "설치된 경우",
+            "AUTO_START_IF_LATEST": "최신 버전인 경우",
+            "Network Retries": "네트워크 재시도 횟수",
+            "Pip Extra Args": "Pip 추가 인수",
+            "Appended to every pip install after the profile pip_args": "프로필의 pip_args 뒤에 모든 pip install에 추가됩니다",
+            "Source Address": "소스 주소",
+            "Local IP address for Python downloads, empty for the OS default": "Python 다운로드에 사용할 로컬 IP 주소, 비워 두면 OS 기본값",
+            "Pip Index Username": "Pip 인덱스 사용자 이름",
+            "Pip Index Password": "Pip 인덱스 비밀번호",
+            "Credentials for a private pip index, passed to pip through the environment": "비공개 pip 인덱스 자격 증명, 환경 변수로 pip에 전달됩니다",
+            "Stored unencrypted in the config file": "설정 파일에 암호화되지 않은 상태로 저장됩니다",
+            "Pip Verbosity": "Pip 출력 수준",
+            "PIP_VERBOSITY_QUIET": "조용히",
+            "PIP_VERBOSITY_NORMAL": "보통",
+            "PIP_VERBOSITY_VERBOSE": "자세히",
+            "PIP_VERBOSITY_VERY_VERBOSE": "매우 자세히",
+            "Suppressed Output": "숨길 출력",
+            "Separated by ;, matching error output is only written to the log file": "; 로 구분하며, 일치하는 오류 출력은 로그 파일에만 기록됩니다",
+            "The installed version's tag was removed upstream.": "설치된 버전의 태그가 업스트림에서 삭제되었습니다.",
+            "Move to {{version}}": "{{version}}(으)로 이동",
+            "Fetch On Startup": "시작 시 가져오기",
+            "FETCH_ON_STARTUP_ALWAYS": "항상",
+            "FETCH_ON_STARTUP_INTERVAL": "하루에 한 번",
+            "FETCH_ON_STARTUP_NEVER": "안 함",
+            "Max Concurrent Installs": "최대 동시 설치 수",
+            "Status Update Interval": "상태 업데이트 간격",
+            "{{count}}s": "{{count}}초",
+            "Stop Apps On Exit": "종료 시 앱 중지",
+            "STOP_APPS_ON_EXIT_OFF": "끄기 (앱 계속 실행)",
+            "STOP_APPS_ON_EXIT_ON": "켜기",
+            "Missing Python": "Python이 없을 때",
+            "MISSING_PYTHON_REPAIR": "Python만 다시 설치",
+            "MISSING_PYTHON_RESET": "앱 전체 삭제",
+            "Network Region": "네트워크 지역",
+            "NETWORK_REGION_CHINA": "중국 (Aliyun pip, 중국 Python 미러)",
+            "NETWORK_REGION_GLOBAL": "글로벌 (PyPI, 공식 Python)",
+            "NETWORK_REGION_CUSTOM": "사용자 지정",
+            "Git Proxy": "Git 프록시",
+            "GIT_PROXY_AUTO": "자동 감지",
+            "GIT_PROXY_OFF": "끄기",
+            "Config Backups": "설정 백업 수",
+            "Switched to profile '{{profile}}'.": "프로필 '{{profile}}'(으)로 전환했습니다.",
+            "Slow Drive Warning": "느린 드라이브 경고",
+            "SLOW_DRIVE_WARNING_ON": "켜기",
+            "SLOW_DRIVE_WARNING_OFF": "끄기",
+            "App Sort Order": "앱 정렬 순서",
+            "APP_SORT_ORDER_RUNNING_FIRST": "실행 중 우선",
+            "APP_SORT_ORDER_ALPHABETICAL": "이름순",
+            "APP_SORT_ORDER_LAST_START": "최근 실행순",
+            "APP_SORT_ORDER_INSTALL_DATE": "설치 날짜",
+            "(Environment Missing)": "(환경 없음)",
+            "Repair": "복구",
+            "Uninstall, Keep Data": "데이터 유지하고 제거",
+            "Started in safe mode: apps were not auto-updated or auto-started. Restart pyappify normally when done.": "안전 모드로 시작했습니다: 앱을 자동 업데이트하거나 자동 시작하지 않았습니다. 작업이 끝나면 pyappify를 정상적으로 다시 시작하세요.",
+            "Second Launch": "중복 실행",
+            "SECOND_LAUNCH_FOCUS_WINDOW": "창 표시",
+            "SECOND_LAUNCH_FORWARD_COMMAND": "명령 실행",
+            "SECOND_LAUNCH_SILENT": "무음",
+            "pyappify is using {{size}} across {{count}} apps": "pyappify가 앱 {{count}}개에 {{size}}를 사용 중입니다",
+            "Diagnose Network": "네트워크 진단",
+            "Pip Index": "Pip 인덱스",
+            "Python Download": "Python 다운로드",
+            "Git Remote": "Git 원격 저장소",
+            "Reachable in {{ms}} ms": "{{ms}}ms 내 연결됨",
+            "Unreachable": "연결 불가",
+            "Roll Back to {{version}}": "{{version}}(으)로 롤백",
+            "The update to {{version}} did not finish.": "{{version}}(으)로의 업데이트가 완료되지 않았습니다.",
+            "Python: {{version}}": "Python: {{version}}",
+            "Requirements: {{requirements}}": "의존성: {{requirements}}",
+            "Launches: {{target}}": "실행 대상: {{target}}",
+            "Pip Index: {{url}}": "Pip 인덱스: {{url}}",
+            "None": "없음",
+            "Default": "기본값",
+            "Proxy": "프록시",
+            "Used by pip, Python downloads and git; git only uses http(s) proxies": "pip, Python 다운로드, git에 사용되며 git은 http(s) 프록시만 사용"
         }
     },
     es: {
@@ -653,7 +1058,88 @@ const resources = {
             "Update Method": "Método de Actualización",
             "MANUAL_UPDATE": "Actualización Manual",
             "AUTO_UPDATE": "Actualización Automática",
-            "IGNORE_UPDATE": "Ignorar Actualizaciones"
+            "IGNORE_UPDATE": "Ignorar Actualizaciones",
+            "Python Download Mirror": "Espejo de Descarga de Python",
+            "PYTHON_MIRROR_AUTO": "Automático (según la región)",
+            "PYTHON_MIRROR_OFFICIAL": "Oficial (python.org, GitHub)",
+            "PYTHON_MIRROR_CHINA": "Espejo de China (Huawei Cloud, ModelScope)",
+            "Auto Start": "Inicio Automático",
+            "AUTO_START_OFF": "Desactivado",
+            "AUTO_START_IF_INSTALLED": "Si Está Instalado",
+            "AUTO_START_IF_LATEST": "Si Es la Última Versión",
+            "Network Retries": "Reintentos de Red",
+            "Pip Extra Args": "Argumentos Extra de Pip",
+            "Appended to every pip install after the profile pip_args": "Se añaden a cada pip install después de los pip_args del perfil",
+            "Source Address": "Dirección de Origen",
+            "Local IP address for Python downloads, empty for the OS default": "Dirección IP local para las descargas de Python, vacía para usar la del sistema",
+            "Pip Index Username": "Usuario del Índice de Pip",
+            "Pip Index Password": "Contraseña del Índice de Pip",
+            "Credentials for a private pip index, passed to pip through the environment": "Credenciales de un índice de pip privado, pasadas a pip mediante el entorno",
+            "Stored unencrypted in the config file": "Se guarda sin cifrar en el archivo de configuración",
+            "Pip Verbosity": "Detalle de Salida de Pip",
+            "PIP_VERBOSITY_QUIET": "Silencioso",
+            "PIP_VERBOSITY_NORMAL": "Normal",
+            "PIP_VERBOSITY_VERBOSE": "Detallado",
+            "PIP_VERBOSITY_VERY_VERBOSE": "Muy Detallado",
+            "Suppressed Output": "Salida Suprimida",
+            "Separated by ;, matching error output is only written to the log file": "Separado por ;, la salida de error que coincida solo se escribe en el archivo de registro",
+            "The installed version's tag was removed upstream.": "La etiqueta de la versión instalada se eliminó en el repositorio de origen.",
+            "Move to {{version}}": "Cambiar a {{version}}",
+            "Fetch On Startup": "Obtener al iniciar",
+            "FETCH_ON_STARTUP_ALWAYS": "Siempre",
+            "FETCH_ON_STARTUP_INTERVAL": "Una vez al día",
+            "FETCH_ON_STARTUP_NEVER": "Nunca",
+            "Max Concurrent Installs": "Instalaciones simultáneas máximas",
+            "Status Update Interval": "Intervalo de actualización de estado",
+            "{{count}}s": "{{count}} s",
+            "Stop Apps On Exit": "Detener apps al salir",
+            "STOP_APPS_ON_EXIT_OFF": "No (mantener apps en ejecución)",
+            "STOP_APPS_ON_EXIT_ON": "Sí",
+            "Missing Python": "Si falta Python",
+            "MISSING_PYTHON_REPAIR": "Reinstalar solo Python",
+            "MISSING_PYTHON_RESET": "Eliminar toda la app",
+            "Network Region": "Región de red",
+            "NETWORK_REGION_CHINA": "China (pip de Aliyun, espejo de Python de China)",
+            "NETWORK_REGION_GLOBAL": "Global (PyPI, Python oficial)",
+            "NETWORK_REGION_CUSTOM": "Personalizado",
+            "Git Proxy": "Proxy de Git",
+            "GIT_PROXY_AUTO": "Detectar automáticamente",
+            "GIT_PROXY_OFF": "Desactivado",
+            "Config Backups": "Copias de seguridad de la configuración",
+            "Switched to profile '{{profile}}'.": "Se cambió al perfil '{{profile}}'.",
+            "Slow Drive Warning": "Aviso de unidad lenta",
+            "SLOW_DRIVE_WARNING_ON": "Activado",
+            "SLOW_DRIVE_WARNING_OFF": "Desactivado",
+            "App Sort Order": "Orden de las aplicaciones",
+            "APP_SORT_ORDER_RUNNING_FIRST": "En ejecución primero",
+            "APP_SORT_ORDER_ALPHABETICAL": "Alfabético",
+            "APP_SORT_ORDER_LAST_START": "Iniciadas recientemente",
+            "APP_SORT_ORDER_INSTALL_DATE": "Fecha de instalación",
+            "(Environment Missing)": "(Entorno ausente)",
+            "Repair": "Reparar",
+            "Uninstall, Keep Data": "Desinstalar y conservar datos",
+            "Started in safe mode: apps were not auto-updated or auto-started. Restart pyappify normally when done.": "Iniciado en modo seguro: las aplicaciones no se actualizaron ni se iniciaron automáticamente. Reinicia pyappify normalmente al terminar.",
+            "Second Launch": "Segundo inicio",
+            "SECOND_LAUNCH_FOCUS_WINDOW": "Mostrar ventana",
+            "SECOND_LAUNCH_FORWARD_COMMAND": "Ejecutar comando",
+            "SECOND_LAUNCH_SILENT": "Silencioso",
+            "pyappify is using {{size}} across {{count}} apps": "pyappify usa {{size}} en {{count}} aplicaciones",
+            "Diagnose Network": "Diagnosticar red",
+            "Pip Index": "Índice de Pip",
+            "Python Download": "Descarga de Python",
+            "Git Remote": "Remoto de Git",
+            "Reachable in {{ms}} ms": "Accesible en {{ms}} ms",
+            "Unreachable": "Inaccesible",
+            "Roll Back to {{version}}": "Revertir a {{version}}",
+            "The update to {{version}} did not finish.": "La actualización a {{version}} no terminó.",
+            "Python: {{version}}": "Python: {{version}}",
+            "Requirements: {{requirements}}": "Dependencias: {{requirements}}",
+            "Launches: {{target}}": "Inicia: {{target}}",
+            "Pip Index: {{url}}": "Índice de Pip: {{url}}",
+            "None": "Ninguno",
+            "Default": "Predeterminado",
+            "Proxy": "Proxy",
+            "Used by pip, Python downloads and git; git only uses http(s) proxies": "Lo usan pip, las descargas de Python y git; git solo usa proxies http(s)"
         }
     },
 };